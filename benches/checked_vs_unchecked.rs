@@ -0,0 +1,53 @@
+// Run this both with and without the `unsafe-unchecked` feature to compare the two:
+//     cargo bench --bench checked_vs_unchecked
+//     cargo bench --bench checked_vs_unchecked --features unsafe-unchecked
+// The benchmarks are named after the configuration, so the results of both runs are kept side by side.
+use at::At;
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+const CONFIG: &str = if cfg!(feature = "unsafe-unchecked") {
+	"unchecked"
+} else {
+	"checked"
+};
+
+fn sum_at(s: &[u64], indices: &[isize]) -> u64 {
+	indices.iter().map(|&i| s.at(i)).sum()
+}
+
+fn sum_ref_at_i128(s: &[u64], indices: &[i128]) -> u64 {
+	indices.iter().map(|&i| *s.ref_at(i)).sum()
+}
+
+fn bump_mut_at(s: &mut [u64], indices: &[isize]) {
+	for &i in indices {
+		*s.mut_at(i) += 1;
+	}
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+	let mut s: Vec<u64> = (0..1024).collect();
+	let positive: Vec<isize> = (0..1024).map(|i| (i * 7) % 1024).collect();
+	let negative: Vec<isize> = positive.iter().map(|i| i - 1024).collect();
+	let wide: Vec<i128> = negative.iter().map(|&i| i as i128).collect();
+
+	c.bench_function(&format!("{CONFIG}/at_positive"), |b| {
+		b.iter(|| sum_at(black_box(&s), black_box(&positive)))
+	});
+
+	c.bench_function(&format!("{CONFIG}/at_negative"), |b| {
+		b.iter(|| sum_at(black_box(&s), black_box(&negative)))
+	});
+
+	c.bench_function(&format!("{CONFIG}/ref_at_i128"), |b| {
+		b.iter(|| sum_ref_at_i128(black_box(&s), black_box(&wide)))
+	});
+
+	c.bench_function(&format!("{CONFIG}/mut_at_negative"), |b| {
+		b.iter(|| bump_mut_at(black_box(&mut s), black_box(&negative)))
+	});
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
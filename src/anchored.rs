@@ -0,0 +1,115 @@
+use crate::{Position, ToIndex, impl_copy_view, split_index};
+#[cfg(feature = "unsafe-unchecked")]
+use core::hint::unreachable_unchecked;
+
+#[cfg(not(feature = "unsafe-unchecked"))]
+#[inline(never)]
+#[cfg_attr(feature = "structured-panic", allow(unused_variables))]
+fn panic_offset_check(rel: impl ToIndex, anchor: usize, len: usize) -> ! {
+	crate::record_oob();
+	#[cfg(feature = "structured-panic")]
+	std::panic::panic_any(crate::IndexError::new(rel, len));
+	#[cfg(not(feature = "structured-panic"))]
+	panic!(
+		"offset out of bounds: the len is {len} but the offset is {rel:?} from the anchor {anchor}"
+	)
+}
+
+/// A slice with an anchor position, which is indexed by offsets relative to the anchor.
+/// Unlike with other indices, negative offsets go backwards from the anchor rather than counting
+/// from the end, so `-1` refers to the element just before the anchor.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::AnchoredSlice;
+/// let a = [1, 2, 3, 4];
+/// let anchored = AnchoredSlice::new(&a, 2);
+///
+/// assert_eq!(anchored.at(0), 3);
+/// assert_eq!(anchored.at(-1), 2);
+/// assert_eq!(anchored.ref_at(1), &4);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AnchoredSlice<'a, T> {
+	slice: &'a [T],
+	anchor: usize,
+}
+
+impl_copy_view!(AnchoredSlice);
+
+impl<'a, T> AnchoredSlice<'a, T> {
+	/// Anchor a slice at a particular position. The anchor may be equal to the length of the slice,
+	/// in which case only negative offsets are in bounds.
+	///
+	/// # Panics
+	/// Panics if the anchor is greater than the length of the slice.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn new(slice: &'a [T], anchor: usize) -> Self {
+		assert!(
+			anchor <= slice.len(),
+			"the len is {} but the anchor is {anchor}",
+			slice.len()
+		);
+		Self { slice, anchor }
+	}
+
+	/// Return the anchor position.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn anchor(&self) -> usize {
+		self.anchor
+	}
+
+	/// Return the whole slice, ignoring the anchor.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn as_slice(&self) -> &'a [T] {
+		self.slice
+	}
+
+	/// Access the element at a particular offset from the anchor of a `Copy` type.
+	/// Panics if the offset is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn at(&self, rel: impl ToIndex) -> T
+	where
+		T: Copy,
+	{
+		*self.ref_at(rel)
+	}
+
+	/// Access the element at a particular offset from the anchor by reference.
+	/// Panics if the offset is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn ref_at(&self, rel: impl ToIndex) -> &'a T {
+		match self.try_ref_at(rel) {
+			Some(element) => element,
+			#[cfg(feature = "unsafe-unchecked")]
+			None => unsafe { unreachable_unchecked() },
+			#[cfg(not(feature = "unsafe-unchecked"))]
+			None => panic_offset_check(rel, self.anchor, self.slice.len()),
+		}
+	}
+
+	/// Access the element at a particular offset from the anchor by reference,
+	/// or return `None` if the offset is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn try_ref_at(&self, rel: impl ToIndex) -> Option<&'a T> {
+		let i = match split_index(rel)? {
+			Position::Forward(i) => self.anchor.checked_add(i)?,
+			Position::FromEnd(k) => self.anchor.checked_sub(k)?,
+		};
+		self.slice.get(i)
+	}
+}
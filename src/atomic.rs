@@ -0,0 +1,50 @@
+use core::sync::atomic::Ordering;
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// An atomic type from `core::sync::atomic`, for use with [`At::load_at`](crate::At::load_at)
+/// and [`At::store_at`](crate::At::store_at). This trait is sealed and can't be implemented outside of this crate.
+pub trait Atomic: sealed::Sealed {
+	/// The type of the value stored in the atomic.
+	type Value;
+
+	/// Load the value with the given ordering, see e.g. [`AtomicUsize::load`](core::sync::atomic::AtomicUsize::load).
+	fn load(&self, order: Ordering) -> Self::Value;
+
+	/// Store a value with the given ordering, see e.g. [`AtomicUsize::store`](core::sync::atomic::AtomicUsize::store).
+	fn store(&self, val: Self::Value, order: Ordering);
+}
+
+macro_rules! impl_atomic {
+	($($width:literal: $($atomic:ident($value:ty)),*;)*) => {$($(
+		#[cfg(target_has_atomic = $width)]
+		impl sealed::Sealed for core::sync::atomic::$atomic {}
+
+		#[cfg(target_has_atomic = $width)]
+		impl Atomic for core::sync::atomic::$atomic {
+			type Value = $value;
+
+			#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+			#[cfg_attr(feature = "no-force-inline", inline)]
+			fn load(&self, order: Ordering) -> $value {
+				self.load(order)
+			}
+
+			#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+			#[cfg_attr(feature = "no-force-inline", inline)]
+			fn store(&self, val: $value, order: Ordering) {
+				self.store(val, order);
+			}
+		}
+	)*)*};
+}
+
+impl_atomic! {
+	"8": AtomicBool(bool), AtomicI8(i8), AtomicU8(u8);
+	"16": AtomicI16(i16), AtomicU16(u16);
+	"32": AtomicI32(i32), AtomicU32(u32);
+	"64": AtomicI64(i64), AtomicU64(u64);
+	"ptr": AtomicIsize(isize), AtomicUsize(usize);
+}
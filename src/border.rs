@@ -0,0 +1,151 @@
+#[cfg(not(feature = "unsafe-unchecked"))]
+use crate::panic_bounds_check;
+use crate::{Position, ToIndex, check_index, split_index};
+#[cfg(feature = "unsafe-unchecked")]
+use core::hint::unreachable_unchecked;
+use core::num::{Saturating, Wrapping};
+
+/// How to handle indices that are out of bounds, for use with [`At::at_with`](crate::At::at_with).
+///
+/// An out-of-bounds index is first converted into a position relative to the start of the slice,
+/// so that a non-negative index `i` is at position `i` and a negative index `-k` is at position `len - k`,
+/// which is before the start if `k > len`. The border mode then maps that position back into the slice.
+/// In every mode, indexing an empty slice panics since there is no element to return,
+/// and so does an index that fits in neither a `usize` nor an `isize`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BorderMode {
+	/// Wrap around, so that the position after the last element is the first element and vice versa.
+	Wrap,
+	/// Clamp to the first or last element.
+	Clamp,
+	/// Reflect off the ends without repeating them, so that the position after the last element is the
+	/// second-to-last element. For a slice of length 4, the positions `-3..=6` map to
+	/// `3, 2, 1, 0, 1, 2, 3, 2, 1, 0`.
+	Mirror,
+	/// Panic, just like [`At::ref_at`](crate::At::ref_at).
+	Panic,
+}
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// An index wrapper that selects a [`BorderMode`], for use with [`At::bordered_at`](crate::At::bordered_at).
+/// [`Wrapping`] selects [`BorderMode::Wrap`] and [`Saturating`] selects [`BorderMode::Clamp`].
+/// This trait is sealed and can't be implemented outside of this crate.
+///
+/// Plain integers can't be given a border mode this way, since implementing this trait for both
+/// them and the wrappers would conflict. Use [`At::at_with`](crate::At::at_with) for those instead.
+pub trait BorderedIndex: sealed::Sealed + Copy {
+	/// The type of the wrapped index.
+	type Index: ToIndex;
+
+	/// Split this into the wrapped index and its border mode.
+	fn into_parts(self) -> (Self::Index, BorderMode);
+}
+
+impl<I: ToIndex> sealed::Sealed for Wrapping<I> {}
+
+impl<I: ToIndex> BorderedIndex for Wrapping<I> {
+	type Index = I;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn into_parts(self) -> (I, BorderMode) {
+		(self.0, BorderMode::Wrap)
+	}
+}
+
+impl<I: ToIndex> sealed::Sealed for Saturating<I> {}
+
+impl<I: ToIndex> BorderedIndex for Saturating<I> {
+	type Index = I;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn into_parts(self) -> (I, BorderMode) {
+		(self.0, BorderMode::Clamp)
+	}
+}
+
+// Maps an out-of-bounds position into the slice. Requires `len > 0`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+#[inline(never)]
+fn apply_border(pos: i128, len: usize, mode: BorderMode) -> Option<usize> {
+	// Positions are within a few multiples of `usize::MAX` of zero, so none of this can overflow.
+	// The results are always in `0..len`, so casting them back is lossless.
+	let n = len as i128;
+	let resolved = match mode {
+		BorderMode::Wrap => pos.rem_euclid(n),
+		BorderMode::Clamp => pos.clamp(0, n - 1),
+		BorderMode::Mirror if n == 1 => 0,
+		BorderMode::Mirror => {
+			let period = 2 * (n - 1);
+			let r = pos.rem_euclid(period);
+			if r < n { r } else { period - r }
+		}
+		BorderMode::Panic => return None,
+	};
+	Some(resolved as usize)
+}
+
+// Resolves an index using the given border mode, or returns `None` if even that fails.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn check_index_with(idx: impl ToIndex, len: usize, mode: BorderMode) -> Option<usize> {
+	if let Some(i) = check_index(idx, len) {
+		return Some(i);
+	}
+	check_position_with(index_position(idx, len)?, len, mode)
+}
+
+// Converts an index into a position relative to the start, which is negative if it's before the start.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn index_position(idx: impl ToIndex, len: usize) -> Option<i128> {
+	Some(match split_index(idx)? {
+		Position::Forward(i) => i as i128,
+		Position::FromEnd(k) => len as i128 - k as i128,
+	})
+}
+
+// Resolves a position (rather than an index) using the given border mode, or returns `None` if even that fails.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn check_position_with(pos: i128, len: usize, mode: BorderMode) -> Option<usize> {
+	if (0..len as i128).contains(&pos) {
+		Some(pos as usize)
+	} else if len == 0 {
+		None
+	} else {
+		apply_border(pos, len, mode)
+	}
+}
+
+// Resolves an index using the given border mode, panicking if even that fails.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn index_with_or_panic(idx: impl ToIndex, len: usize, mode: BorderMode) -> usize {
+	match check_index_with(idx, len, mode) {
+		Some(i) => i,
+		#[cfg(feature = "unsafe-unchecked")]
+		None => unsafe { unreachable_unchecked() },
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		None => panic_bounds_check(idx, len),
+	}
+}
+
+// Resolves a position (rather than an index) using the given border mode, panicking if even that fails.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn position_with_or_panic(pos: i128, len: usize, mode: BorderMode) -> usize {
+	match check_position_with(pos, len, mode) {
+		Some(i) => i,
+		#[cfg(feature = "unsafe-unchecked")]
+		None => unsafe { unreachable_unchecked() },
+		// Report the index that refers to this position, so that a position before the start is negative.
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		None => panic_bounds_check(if pos < 0 { pos - len as i128 } else { pos }, len),
+	}
+}
@@ -0,0 +1,71 @@
+use crate::{ToIndex, index_or_panic};
+use core::cell::{Ref, RefCell, RefMut};
+
+/// This trait provides indexing helpers that borrow through a `RefCell`, so that the
+/// returned guard points directly at the element instead of at the whole slice.
+///
+/// The methods are named differently from those of [`At`](crate::At), since `At` is implemented
+/// for every type and calls with the same name would be ambiguous.
+pub trait RefCellAt<S: ?Sized> {
+	/// Immutably borrow the cell and access a particular index of its contents.
+	/// Panics if the index is out of bounds or if the cell is currently mutably borrowed.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::RefCellAt;
+	/// use std::cell::RefCell;
+	/// let cell = RefCell::new(vec![8, 2, 1, 0]);
+	///
+	/// assert_eq!(*cell.borrow_at(-1), 0);
+	/// # }
+	/// ```
+	fn borrow_at<T>(&self, idx: impl ToIndex) -> Ref<'_, T>
+	where
+		S: AsRef<[T]>;
+
+	/// Mutably borrow the cell and access a particular index of its contents.
+	/// Panics if the index is out of bounds or if the cell is currently borrowed.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::RefCellAt;
+	/// use std::cell::RefCell;
+	/// let cell = RefCell::new(vec![8, 2, 1, 0]);
+	///
+	/// *cell.borrow_mut_at(-1) += 5;
+	/// assert_eq!(*cell.borrow(), [8, 2, 1, 5]);
+	/// # }
+	/// ```
+	fn borrow_mut_at<T>(&self, idx: impl ToIndex) -> RefMut<'_, T>
+	where
+		S: AsMut<[T]>;
+}
+
+impl<S: ?Sized> RefCellAt<S> for RefCell<S> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn borrow_at<T>(&self, idx: impl ToIndex) -> Ref<'_, T>
+	where
+		S: AsRef<[T]>,
+	{
+		Ref::map(self.borrow(), |s| {
+			let slice = s.as_ref();
+			&slice[index_or_panic(idx, slice.len())]
+		})
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn borrow_mut_at<T>(&self, idx: impl ToIndex) -> RefMut<'_, T>
+	where
+		S: AsMut<[T]>,
+	{
+		RefMut::map(self.borrow_mut(), |s| {
+			let slice = s.as_mut();
+			let len = slice.len();
+			&mut slice[index_or_panic(idx, len)]
+		})
+	}
+}
@@ -0,0 +1,29 @@
+use crate::{ToIndex, check_index};
+use core::ffi::CStr;
+
+/// This trait provides the `byte_at` method for C strings.
+pub trait CStrAt {
+	/// Return the byte at a particular index, or `None` if the index is out of bounds.
+	/// The terminating NUL byte isn't included, so `-1` refers to the last byte of the contents.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::CStrAt;
+	/// let s = c"hello";
+	///
+	/// assert_eq!(s.byte_at(-1), Some(b'o'));
+	/// assert_eq!(s.byte_at(5), None);
+	/// # }
+	/// ```
+	fn byte_at(&self, idx: impl ToIndex) -> Option<u8>;
+}
+
+impl CStrAt for CStr {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn byte_at(&self, idx: impl ToIndex) -> Option<u8> {
+		let bytes = self.to_bytes();
+		check_index(idx, bytes.len()).map(|i| bytes[i])
+	}
+}
@@ -0,0 +1,100 @@
+use crate::border::{check_position_with, index_position};
+use crate::{BorderMode, ToIndex, impl_copy_view};
+
+/// A cursor that moves back and forth over a slice, as created by [`At::cursor_at`](crate::At::cursor_at).
+///
+/// The cursor is at a position relative to the start of the slice, which may be out of bounds.
+/// The [`BorderMode`] of the cursor decides how out-of-bounds positions are handled when moving
+/// and peeking: with [`BorderMode::Panic`], they're left as they are and refer to no element at all,
+/// while every other mode maps them back into the slice. Despite its name, `BorderMode::Panic` never
+/// makes the cursor panic.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::{At, BorderMode};
+/// let a = [1, 2, 3];
+/// let mut cursor = a.cursor_at(-1, BorderMode::Wrap);
+///
+/// assert_eq!(cursor.current(), Some(&3));
+/// assert_eq!(cursor.peek(1), Some(&1));
+///
+/// cursor.advance(2);
+/// assert_eq!(cursor.current(), Some(&2));
+/// assert_eq!(cursor.position(), Some(1));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Cursor<'a, T> {
+	slice: &'a [T],
+	// `None` if the position fits in neither a `usize` nor an `isize`, which only happens after seeking.
+	pos: Option<i128>,
+	mode: BorderMode,
+}
+
+impl_copy_view!(Cursor);
+
+impl<'a, T> Cursor<'a, T> {
+	/// Create a cursor at the start of a slice.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn new(slice: &'a [T], mode: BorderMode) -> Self {
+		Self {
+			slice,
+			pos: Some(0),
+			mode,
+		}
+	}
+
+	/// Return the index of the element that the cursor is at, or `None` if it's out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn position(&self) -> Option<usize> {
+		let pos = self.pos?;
+		check_position_with(pos, self.slice.len(), BorderMode::Panic)
+	}
+
+	/// Return the element that the cursor is at, or `None` if it's out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn current(&self) -> Option<&'a T> {
+		self.position().map(|i| &self.slice[i])
+	}
+
+	/// Return the element `offset` positions away from the cursor, using the border mode of the cursor.
+	/// Returns `None` if that still doesn't refer to an element.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn peek(&self, offset: isize) -> Option<&'a T> {
+		let pos = self.pos? + offset as i128;
+		check_position_with(pos, self.slice.len(), self.mode).map(|i| &self.slice[i])
+	}
+
+	/// Move the cursor by `by` positions, using the border mode of the cursor.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub fn advance(&mut self, by: isize) {
+		self.pos = self
+			.pos
+			.map(|pos| self.settle(pos.saturating_add(by as i128)));
+	}
+
+	/// Move the cursor to a particular index, using the border mode of the cursor.
+	/// Negative indices count from the end, as usual.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub fn seek(&mut self, idx: impl ToIndex) {
+		self.pos = index_position(idx, self.slice.len()).map(|pos| self.settle(pos));
+	}
+
+	// Maps a position back into the slice, unless the border mode leaves it out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn settle(&self, pos: i128) -> i128 {
+		check_position_with(pos, self.slice.len(), self.mode).map_or(pos, |i| i as i128)
+	}
+}
@@ -0,0 +1,91 @@
+use crate::{ToIndex, index_or_panic};
+use heapless::Deque;
+
+/// This trait provides indexing helpers for `heapless::Deque`, which can't use [`At`](crate::At)
+/// since its elements aren't necessarily contiguous. Requires the `heapless` feature.
+///
+/// `heapless::Vec` doesn't need this trait: it implements `AsRef<[T]>`, so `At` works directly.
+///
+/// The methods are named differently from those of `At`, since `At` is implemented for every type
+/// and calls with the same name would be ambiguous.
+pub trait DequeAt<T> {
+	/// Access a particular index of a `Copy` type, where index `0` is the front of the deque.
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::DequeAt;
+	/// let mut deque = heapless::Deque::<i32, 4>::new();
+	/// deque.push_back(2).unwrap();
+	/// deque.push_back(3).unwrap();
+	/// deque.push_front(1).unwrap();
+	///
+	/// assert_eq!(deque.deque_at(-1), 3);
+	/// assert_eq!(deque.deque_at(0), 1);
+	/// # }
+	/// ```
+	fn deque_at(&self, idx: impl ToIndex) -> T
+	where
+		T: Copy;
+
+	/// Access a particular index by reference, where index `0` is the front of the deque.
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::DequeAt;
+	/// let mut deque = heapless::Deque::<i32, 4>::new();
+	/// deque.push_back(1).unwrap();
+	/// deque.push_back(2).unwrap();
+	///
+	/// assert_eq!(deque.deque_ref_at(-2), &1);
+	/// # }
+	/// ```
+	fn deque_ref_at(&self, idx: impl ToIndex) -> &T;
+
+	/// Access a particular index by mutable reference, where index `0` is the front of the deque.
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::DequeAt;
+	/// let mut deque = heapless::Deque::<i32, 4>::new();
+	/// deque.push_back(1).unwrap();
+	/// deque.push_back(2).unwrap();
+	///
+	/// *deque.deque_mut_at(-1) = 0;
+	/// assert_eq!(deque.back(), Some(&0));
+	/// # }
+	/// ```
+	fn deque_mut_at(&mut self, idx: impl ToIndex) -> &mut T;
+}
+
+impl<T, const N: usize> DequeAt<T> for Deque<T, N> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn deque_at(&self, idx: impl ToIndex) -> T
+	where
+		T: Copy,
+	{
+		*self.deque_ref_at(idx)
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn deque_ref_at(&self, idx: impl ToIndex) -> &T {
+		let i = index_or_panic(idx, self.len());
+		// SAFETY: `index_or_panic` only returns indices that are in bounds.
+		unsafe { self.get_unchecked(i) }
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn deque_mut_at(&mut self, idx: impl ToIndex) -> &mut T {
+		let i = index_or_panic(idx, self.len());
+		// SAFETY: `index_or_panic` only returns indices that are in bounds.
+		unsafe { self.get_unchecked_mut(i) }
+	}
+}
@@ -0,0 +1,172 @@
+use core::fmt;
+use core::ops::{Add, Sub};
+
+/// The end of a slice, for writing indices relative to the end. `End - k` is the index `k` elements
+/// before the end, so `End - 1` refers to the last element, just like `-1`.
+///
+/// `End` itself refers to the position one past the last element, which can't be expressed as an index.
+/// So it's never in bounds, not even as a range bound; use an unbounded range like `1..` instead.
+/// Likewise, any index past the end (such as `End + 1`) is never in bounds.
+///
+/// These indices are resolved by converting them into plain negative integers, so `End - k` behaves
+/// exactly like the index `-k`. Plain negative integers keep working as before; `End` is just a more
+/// explicit way of writing them.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::{At, End};
+/// let a = [1, 2, 3, 4];
+///
+/// assert_eq!(a.ref_at(End - 1), &4);
+/// assert_eq!(a.slice_at(End - 3..End - 1), &[2, 3]);
+/// assert_eq!(a.classify_at(End), at::IndexOutcome::Unrepresentable);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct End;
+
+/// An index relative to the end of a slice, created by subtracting from [`End`] or directly,
+/// so `FromEnd(1)` is the same as `End - 1` and refers to the last element.
+/// Unlike `-0`, which is just `0`, `FromEnd(0)` is `End` itself and never in bounds.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::{At, FromEnd};
+/// let a = [1, 2, 3];
+///
+/// assert_eq!(a.ref_at(FromEnd(1)), &3);
+/// assert_eq!(a.try_ref_at(FromEnd(0)), None);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FromEnd(pub usize);
+
+/// An index counting forward from the start of a slice. This is equivalent to a plain `usize`,
+/// but makes the intent explicit at call sites where [`FromEnd`] is also used.
+///
+/// # Examples
+/// ```
+/// use at::{At, Forward};
+/// let a = [1, 2, 3];
+///
+/// assert_eq!(a.ref_at(Forward(0)), &1);
+/// assert_eq!(a.try_ref_at(Forward(3)), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Forward(pub usize);
+
+impl FromEnd {
+	/// Return the distance from the end, so that `End - 1` has a distance of `1`.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn distance(self) -> usize {
+		self.0
+	}
+}
+
+impl Sub<usize> for End {
+	type Output = FromEnd;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn sub(self, rhs: usize) -> FromEnd {
+		FromEnd(rhs)
+	}
+}
+
+// Distances that overflow are far too large to be in bounds anyway, so they saturate.
+impl Sub<usize> for FromEnd {
+	type Output = Self;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn sub(self, rhs: usize) -> Self {
+		Self(self.0.saturating_add(rhs))
+	}
+}
+
+// Indices past the end are never in bounds, so they're all equivalent to `End` itself.
+impl Add<usize> for FromEnd {
+	type Output = Self;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn add(self, rhs: usize) -> Self {
+		Self(self.0.saturating_sub(rhs))
+	}
+}
+
+impl fmt::Debug for FromEnd {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.0 {
+			0 => write!(f, "End"),
+			k => write!(f, "End - {k}"),
+		}
+	}
+}
+
+// These conversions are what make `FromEnd` usable as an index.
+impl TryFrom<FromEnd> for isize {
+	type Error = ();
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_from(idx: FromEnd) -> Result<Self, ()> {
+		if idx.0 == 0 {
+			return Err(());
+		}
+		0isize.checked_sub_unsigned(idx.0).ok_or(())
+	}
+}
+
+impl TryFrom<FromEnd> for usize {
+	type Error = ();
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_from(_: FromEnd) -> Result<Self, ()> {
+		Err(())
+	}
+}
+
+// A forward index is never treated as counting from the end, even if it fits in an `isize`.
+impl TryFrom<Forward> for isize {
+	type Error = ();
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_from(_: Forward) -> Result<Self, ()> {
+		Err(())
+	}
+}
+
+impl From<Forward> for usize {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from(idx: Forward) -> Self {
+		idx.0
+	}
+}
+
+impl TryFrom<End> for isize {
+	type Error = ();
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_from(_: End) -> Result<Self, ()> {
+		Err(())
+	}
+}
+
+impl TryFrom<End> for usize {
+	type Error = ();
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_from(_: End) -> Result<Self, ()> {
+		Err(())
+	}
+}
@@ -0,0 +1,188 @@
+use crate::{ToIndex, to_signed};
+use core::fmt;
+
+/// An out-of-bounds error that doesn't depend on the type of the index.
+///
+/// The index is stored as an `isize`. Indices that don't fit are saturated to `isize::MIN` or `isize::MAX`,
+/// which are never valid for slices of non-zero-sized types. Use [`OutOfBounds`] to keep the original index.
+///
+/// With the `structured-panic` feature, this is the payload of out-of-bounds panics for element indices.
+/// A panic hook can retrieve it with `PanicHookInfo::payload().downcast_ref::<IndexError>()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndexError {
+	/// The index that was out of bounds.
+	pub index: isize,
+	/// The length of the slice.
+	pub len: usize,
+}
+
+impl IndexError {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn new(idx: impl ToIndex, len: usize) -> Self {
+		Self {
+			index: saturate_index(idx),
+			len,
+		}
+	}
+}
+
+impl fmt::Display for IndexError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"index out of bounds: the len is {} but the index is {}",
+			self.len, self.index
+		)
+	}
+}
+
+impl core::error::Error for IndexError {}
+
+/// An out-of-bounds error with a suggestion for a nearby index that is in bounds,
+/// for showing actionable messages to users. Returned by [`At::at_ctx`](crate::At::at_ctx).
+///
+/// The requested index is saturated to an `isize` just like in [`IndexError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OobContext {
+	/// The index that was out of bounds.
+	pub requested: isize,
+	/// The length of the slice.
+	pub len: usize,
+	/// The in-bounds index closest to the requested one, or `None` if the slice is empty.
+	/// This is `-1` (the last element) for indices past the end and `0` (the first element)
+	/// for negative indices before the start.
+	pub nearest_valid: Option<isize>,
+}
+
+impl OobContext {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn new(idx: impl ToIndex, len: usize) -> Self {
+		let requested = saturate_index(idx);
+		let nearest_valid = match (len, requested < 0) {
+			(0, _) => None,
+			(_, true) => Some(0),
+			(_, false) => Some(-1),
+		};
+		Self {
+			requested,
+			len,
+			nearest_valid,
+		}
+	}
+}
+
+impl fmt::Display for OobContext {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"index out of bounds: the len is {} but the index is {}",
+			self.len, self.requested
+		)?;
+		match self.nearest_valid {
+			Some(nearest) => write!(f, "; did you mean {nearest}?"),
+			None => Ok(()),
+		}
+	}
+}
+
+impl core::error::Error for OobContext {}
+
+/// An out-of-bounds error that keeps the original index, with its original type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfBounds<I> {
+	/// The index that was out of bounds.
+	pub index: I,
+	/// The length of the slice.
+	pub len: usize,
+}
+
+impl<I: fmt::Debug> fmt::Display for OutOfBounds<I> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"index out of bounds: the len is {} but the index is {:?}",
+			self.len, self.index
+		)
+	}
+}
+
+impl<I: fmt::Debug> core::error::Error for OutOfBounds<I> {}
+
+impl<I: ToIndex> From<OutOfBounds<I>> for IndexError {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from(err: OutOfBounds<I>) -> Self {
+		Self::new(err.index, err.len)
+	}
+}
+
+// Converts an index to an `isize`, saturating indices that don't fit.
+#[cold]
+pub(crate) fn saturate_index(idx: impl ToIndex) -> isize {
+	// Records whether the `Debug` output of an index starts with a minus sign.
+	struct Negative(Option<bool>);
+
+	impl fmt::Write for Negative {
+		fn write_str(&mut self, s: &str) -> fmt::Result {
+			if self.0.is_none() {
+				self.0 = s.chars().next().map(|c| c == '-');
+			}
+			Ok(())
+		}
+	}
+
+	if let Ok(unsigned_index) = TryInto::<usize>::try_into(idx) {
+		return isize::try_from(unsigned_index).unwrap_or(isize::MAX);
+	}
+	if let Some(signed_index) = to_signed(idx) {
+		return signed_index;
+	}
+
+	// The index fits in neither type (or negative indices are disabled),
+	// so its sign can't be determined through the conversions.
+	let mut negative = Negative(None);
+	let _ = fmt::write(&mut negative, format_args!("{idx:?}"));
+	if negative.0 == Some(true) {
+		isize::MIN
+	} else {
+		isize::MAX
+	}
+}
+
+/// Why a range couldn't be resolved, as returned by [`At::slice_at_ctx`](crate::At::slice_at_ctx).
+///
+/// The bounds that are out of range are stored as an `isize`. Like in [`IndexError`], bounds that don't fit
+/// are saturated to `isize::MIN` or `isize::MAX`, depending on their sign.
+///
+/// With the `structured-panic` feature, this is the payload of panics for ranges that can't be resolved,
+/// such as those of [`At::slice_at`](crate::At::slice_at).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RangeError {
+	/// The start of the range is out of bounds.
+	StartOutOfRange(isize),
+	/// The end of the range is out of bounds.
+	EndOutOfRange(isize),
+	/// Both bounds are in bounds, but the resolved start is after the resolved end.
+	Reversed {
+		/// The resolved start.
+		start: usize,
+		/// The resolved end.
+		end: usize,
+	},
+}
+
+impl fmt::Display for RangeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::StartOutOfRange(start) => write!(f, "range start {start} is out of bounds"),
+			Self::EndOutOfRange(end) => write!(f, "range end {end} is out of bounds"),
+			Self::Reversed { start, end } => {
+				write!(f, "range starts at {start} but ends at {end}")
+			}
+		}
+	}
+}
+
+impl core::error::Error for RangeError {}
@@ -0,0 +1,78 @@
+use crate::{ToIndex, check_index, index_or_panic};
+use core::slice::Iter;
+
+/// An iterator over the elements at a slice of indices, as returned by [`At::gather_at`](crate::At::gather_at).
+/// Panics when it reaches an index that's out of bounds.
+#[derive(Debug)]
+pub struct GatherIter<'a, T, I> {
+	slice: &'a [T],
+	indices: Iter<'a, I>,
+}
+
+/// An iterator over the elements at a slice of indices, as returned by [`At::try_gather_at`](crate::At::try_gather_at).
+/// Yields `None` for indices that are out of bounds.
+#[derive(Debug)]
+pub struct TryGatherIter<'a, T, I> {
+	slice: &'a [T],
+	indices: Iter<'a, I>,
+}
+
+impl<'a, T, I> GatherIter<'a, T, I> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn new(slice: &'a [T], indices: &'a [I]) -> Self {
+		Self {
+			slice,
+			indices: indices.iter(),
+		}
+	}
+}
+
+impl<'a, T, I> TryGatherIter<'a, T, I> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn new(slice: &'a [T], indices: &'a [I]) -> Self {
+		Self {
+			slice,
+			indices: indices.iter(),
+		}
+	}
+}
+
+impl<'a, T, I: ToIndex> Iterator for GatherIter<'a, T, I> {
+	type Item = &'a T;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn next(&mut self) -> Option<&'a T> {
+		let &idx = self.indices.next()?;
+		Some(&self.slice[index_or_panic(idx, self.slice.len())])
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.indices.size_hint()
+	}
+}
+
+impl<'a, T, I: ToIndex> Iterator for TryGatherIter<'a, T, I> {
+	type Item = Option<&'a T>;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn next(&mut self) -> Option<Option<&'a T>> {
+		let &idx = self.indices.next()?;
+		Some(check_index(idx, self.slice.len()).map(|i| &self.slice[i]))
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.indices.size_hint()
+	}
+}
+
+impl<T, I: ToIndex> ExactSizeIterator for GatherIter<'_, T, I> {}
+
+impl<T, I: ToIndex> ExactSizeIterator for TryGatherIter<'_, T, I> {}
@@ -0,0 +1,46 @@
+use crate::{Position, ToIndex, split_index};
+use alloc::collections::VecDeque;
+
+/// This trait provides the `nth_at` method for iterators. Requires the `alloc` feature.
+pub trait IterAt: Iterator + Sized {
+	/// Return the element at a particular index, or `None` if the iterator is too short.
+	/// Non-negative indices behave exactly like `Iterator::nth`.
+	///
+	/// A negative index `-k` can only be resolved once the iterator is exhausted, so it always consumes
+	/// the whole iterator. While doing so, it buffers the last `k` elements in a ring buffer,
+	/// which takes `O(k)` memory (but never more than the length of the iterator).
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::IterAt;
+	///
+	/// assert_eq!((0..10).nth_at(2), Some(2));
+	/// assert_eq!((0..10).nth_at(-2), Some(8));
+	/// assert_eq!((0..10).nth_at(-11), None);
+	/// # }
+	/// ```
+	#[inline]
+	fn nth_at(mut self, idx: impl ToIndex) -> Option<Self::Item> {
+		let from_end = match split_index(idx)? {
+			Position::Forward(i) => return self.nth(i),
+			Position::FromEnd(k) => k,
+		};
+
+		let mut buffer = VecDeque::new();
+		for item in self {
+			if buffer.len() == from_end {
+				buffer.pop_front();
+			}
+			buffer.push_back(item);
+		}
+
+		if buffer.len() == from_end {
+			buffer.pop_front()
+		} else {
+			None
+		}
+	}
+}
+
+impl<I: Iterator> IterAt for I {}
@@ -1,4 +1,5 @@
 #![no_std]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![warn(clippy::pedantic)]
 #![deny(missing_docs)]
 #![allow(clippy::inline_always)]
@@ -20,51 +21,643 @@
 //!
 //! # Examples
 //! ```
+//! # #[cfg(not(feature = "no-negative"))] {
 //! use at::At;
 //!
 //! let mut v = vec![8, 2, 1, 0];
 //! assert_eq!(v.at(-1), 0);
 //! assert_eq!(v.ref_at(2), &1);
 //! assert_eq!(v.mut_at(-3), &mut 2);
+//! # }
 //! ```
+//!
+//! # Features
+//! - `alloc`: extension methods for `Vec` and iterators, see [`VecAt`] and [`IterAt`], as well as [`At::join_at`]
+//! - `std`: indexing of path components, see [`PathAt`]; implies `alloc`
+//! - `structured-panic`: out-of-bounds panics carry an [`IndexError`] payload instead of a message,
+//!   or a [`RangeError`] payload for ranges, so that a panic hook can downcast it; implies `std`.
+//!   Reads and writes of several elements that don't fit still panic with a message
+//! - `bytemuck`: reinterpreting bytes with [`At::cast_at`]
+//! - `heapless`: indexing of `heapless::Deque`, see [`DequeAt`]; requires Rust 1.87, like `heapless` itself
+//! - `instrument`: counts out-of-bounds panics, see [`oob_count`]
+//! - `ndarray`: indexing of one-dimensional `ndarray` arrays, see [`NdAt`]
+//! - `no-force-inline`: uses `#[inline]` instead of `#[inline(always)]` for everything, leaving inlining up to
+//!   the compiler; this may reduce compile times and binary size for large projects at some cost in performance
+//! - `no-negative`: disables negative indices, so that indices only need to implement `TryInto<usize>`;
+//!   negative indices then fail to convert and are treated as out of bounds, and methods that return negative
+//!   indices, such as [`At::find_map_at`], aren't available. This removes the code for resolving negative
+//!   indices, which may help on size-constrained targets
+//! - `simd`: loading SIMD vectors with [`At::simd_at`]; requires nightly Rust
+//! - `unsafe-unchecked`: disables all bounds checks, see above
+//! - `zerocopy`: reading structs from bytes with [`At::read_at`]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+use border::{check_index_with, index_with_or_panic, position_with_or_panic};
 #[cfg(feature = "unsafe-unchecked")]
 use core::hint::unreachable_unchecked;
+use core::iter::{Chain, StepBy};
+use core::ops::{Add, AddAssign, Bound, Mul, Range, Sub};
+use core::ptr::NonNull;
+use core::slice::{Iter, IterMut, RChunks, RChunksMut, Windows};
+use core::sync::atomic::Ordering;
+use read::read_int_methods;
+
+mod anchored;
+mod atomic;
+mod border;
+mod cell;
+mod cstr;
+mod cursor;
+#[cfg(feature = "heapless")]
+mod deque;
+mod end;
+mod error;
+mod gather;
+#[cfg(feature = "alloc")]
+mod iter;
+mod maybe_owned;
+mod must_use;
+#[cfg(feature = "ndarray")]
+mod nd;
+mod nonempty;
+#[cfg(feature = "std")]
+mod path;
+mod range;
+mod read;
+mod rev;
+mod ring;
+#[cfg(feature = "alloc")]
+mod sliding;
+mod stack;
+mod strided;
+mod torus;
+mod validated;
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub use anchored::AnchoredSlice;
+pub use atomic::Atomic;
+pub use border::{BorderMode, BorderedIndex};
+pub use cell::RefCellAt;
+pub use cstr::CStrAt;
+pub use cursor::Cursor;
+#[cfg(feature = "heapless")]
+pub use deque::DequeAt;
+pub use end::{End, Forward, FromEnd};
+pub use error::{IndexError, OobContext, OutOfBounds, RangeError};
+pub use gather::{GatherIter, TryGatherIter};
+#[cfg(feature = "alloc")]
+pub use iter::IterAt;
+pub use maybe_owned::MaybeOwned;
+pub use must_use::MustUse;
+#[cfg(feature = "ndarray")]
+pub use nd::NdAt;
+pub use nonempty::NonEmptySlice;
+#[cfg(feature = "std")]
+pub use path::PathAt;
+pub use range::{ResolvedRange, SignedRange};
+pub use rev::RevSlice;
+pub use ring::Ring;
+pub use stack::Stack;
+pub use strided::StridedView;
+pub use torus::TorusGrid;
+pub use validated::Validated;
+#[cfg(feature = "alloc")]
+pub use vec::VecAt;
 
 mod private {
+	#[cfg(not(feature = "no-negative"))]
 	pub trait ToIndex: TryInto<isize> + TryInto<usize> + core::fmt::Debug + Copy {}
+	#[cfg(not(feature = "no-negative"))]
 	impl<T: TryInto<isize> + TryInto<usize> + core::fmt::Debug + Copy> ToIndex for T {}
+
+	#[cfg(feature = "no-negative")]
+	pub trait ToIndex: TryInto<usize> + core::fmt::Debug + Copy {}
+	#[cfg(feature = "no-negative")]
+	impl<T: TryInto<usize> + core::fmt::Debug + Copy> ToIndex for T {}
 }
 
 // Trait alias for TryInto<isize> + TryInto<usize> + core::fmt::Debug + Copy
+// (without the TryInto<isize> bound if the `no-negative` feature is enabled)
 use private::ToIndex;
 
-#[inline(always)]
-fn check_index(idx: impl ToIndex, len: usize) -> Option<usize> {
-	let resolved = if let Ok(unsigned_index) = idx.try_into() {
-		unsigned_index
+// Implements `Clone` and `Copy` for a view over a slice of `T`.
+// Implemented manually, since deriving these would require `T: Clone`.
+macro_rules! impl_copy_view {
+	($view:ident) => {
+		impl<T> Clone for $view<'_, T> {
+			fn clone(&self) -> Self {
+				*self
+			}
+		}
+
+		impl<T> Copy for $view<'_, T> {}
+	};
+}
+
+pub(crate) use impl_copy_view;
+
+// Converts an index to an `isize`. Only called for indices that don't fit in a `usize`.
+#[cfg(not(feature = "no-negative"))]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn to_signed(idx: impl ToIndex) -> Option<isize> {
+	idx.try_into().ok()
+}
+
+// With the `no-negative` feature, indices that don't fit in a `usize` are never valid.
+#[cfg(feature = "no-negative")]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn to_signed(_: impl ToIndex) -> Option<isize> {
+	None
+}
+
+// Resolves a possibly negative index against `len` without comparing the result to `len`.
+// Callers must reject any result greater than `len`.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn resolve_unbounded(idx: impl ToIndex, len: usize) -> Option<usize> {
+	if let Ok(unsigned_index) = idx.try_into() {
+		Some(unsigned_index)
 	} else {
-		let signed_index = idx.try_into().ok()?;
-		// If this overflows, the index is guaranteed invalid (this is handled at the end of this function).
+		let signed_index = to_signed(idx)?;
+		// If this overflows, the index is guaranteed invalid (this is handled by the callers).
 		// Proof: `signed_index` must be negative; otherwise, the previous branch would have succeeded.
 		// Thus `signed_index` is any negative number in `isize::MIN..0`. After the addition,
 		// `resolved` is in `len + isize::MIN..len`. If the length is extremely large, that is `len > isize::MAX`,
 		// (only possible for ZST slices) overflow does not occur. Otherwise, the wrapped range
 		// is `len + isize::MIN..0` which becomes `len + isize::MAX + 1..=usize::MAX`. But since we
-		// know that `len` is at most `isize::MAX` in this case, the wrapped range is always invalid.
+		// know that `len` is at most `isize::MAX` in this case, the wrapped range is always greater than `len`.
 		// Therefore, we use the wrapping method to discourage the compiler from adding pointless runtime checks.
-		len.wrapping_add_signed(signed_index)
-	};
+		Some(len.wrapping_add_signed(signed_index))
+	}
+}
 
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn check_index(idx: impl ToIndex, len: usize) -> Option<usize> {
+	let resolved = resolve_unbounded(idx, len)?;
 	(resolved < len).then_some(resolved)
 }
 
+// Like `check_index`, but also accepts `len` itself, which is a valid range bound.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn check_bound(idx: impl ToIndex, len: usize) -> Option<usize> {
+	let resolved = resolve_unbounded(idx, len)?;
+	(resolved <= len).then_some(resolved)
+}
+
+#[cfg(feature = "instrument")]
+static OOB_COUNT: core::sync::atomic::AtomicU64 = core::sync::atomic::AtomicU64::new(0);
+
+/// Return the number of out-of-bounds panics raised by this crate so far, across all threads.
+/// Fallible methods that return `None` or an error aren't counted, and neither is anything
+/// with the `unsafe-unchecked` feature, which disables the checks. Requires the `instrument` feature.
+///
+/// # Examples
+/// ```
+/// let before = at::oob_count();
+/// // ... code that might index out of bounds ...
+/// let hits = at::oob_count() - before;
+/// ```
+#[cfg(feature = "instrument")]
+#[must_use]
+pub fn oob_count() -> u64 {
+	OOB_COUNT.load(Ordering::Relaxed)
+}
+
+// Records an out-of-bounds panic for `oob_count`. This does nothing without the `instrument` feature.
+#[cfg(not(feature = "unsafe-unchecked"))]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn record_oob() {
+	#[cfg(feature = "instrument")]
+	OOB_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
 #[cfg(not(feature = "unsafe-unchecked"))]
 #[inline(never)]
 fn panic_bounds_check(idx: impl ToIndex, len: usize) -> ! {
+	record_oob();
+	#[cfg(feature = "structured-panic")]
+	std::panic::panic_any(IndexError::new(idx, len));
+	#[cfg(not(feature = "structured-panic"))]
 	panic!("index out of bounds: the len is {len} but the index is {idx:?}")
 }
 
+// Like `panic_bounds_check`, but names the axis of a multidimensional index in the message.
+#[cfg(not(feature = "unsafe-unchecked"))]
+#[inline(never)]
+#[cfg_attr(feature = "structured-panic", allow(unused_variables))]
+fn panic_axis_check(axis: &str, idx: impl ToIndex, len: usize) -> ! {
+	record_oob();
+	#[cfg(feature = "structured-panic")]
+	std::panic::panic_any(IndexError::new(idx, len));
+	#[cfg(not(feature = "structured-panic"))]
+	panic!("{axis} index out of bounds: the len is {len} but the index is {idx:?}")
+}
+
+#[cfg(not(feature = "unsafe-unchecked"))]
+#[inline(never)]
+fn panic_range_check(range: SignedRange, len: usize) -> ! {
+	record_oob();
+	#[cfg(feature = "structured-panic")]
+	std::panic::panic_any(
+		range
+			.resolve_or_error(len)
+			.expect_err("the range should be out of bounds"),
+	);
+	#[cfg(not(feature = "structured-panic"))]
+	panic!("range out of bounds: the len is {len} but the range is {range:?}")
+}
+
+#[cfg(not(feature = "unsafe-unchecked"))]
+#[inline(never)]
+fn panic_write_check(idx: impl ToIndex, width: usize, len: usize) -> ! {
+	record_oob();
+	panic!(
+		"write out of bounds: the len is {len} but the write is {width} elements at index {idx:?}"
+	)
+}
+
+// Resolves an element index, panicking if it's out of bounds.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn index_or_panic(idx: impl ToIndex, len: usize) -> usize {
+	match check_index(idx, len) {
+		Some(i) => i,
+		#[cfg(feature = "unsafe-unchecked")]
+		None => unsafe { unreachable_unchecked() },
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		None => panic_bounds_check(idx, len),
+	}
+}
+
+// Concrete version of `index_or_panic` for a `usize`, which skips the conversions entirely.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn uindex_or_panic(idx: usize, len: usize) -> usize {
+	if idx < len {
+		return idx;
+	}
+	#[cfg(feature = "unsafe-unchecked")]
+	unsafe {
+		unreachable_unchecked()
+	}
+	#[cfg(not(feature = "unsafe-unchecked"))]
+	panic_bounds_check(idx, len)
+}
+
+// Concrete version of `index_or_panic` for an `isize`. See `resolve_unbounded` for why the addition can wrap.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn iindex_or_panic(idx: isize, len: usize) -> usize {
+	let resolved = if idx >= 0 {
+		Some(idx.unsigned_abs())
+	} else if cfg!(feature = "no-negative") {
+		None
+	} else {
+		Some(len.wrapping_add_signed(idx))
+	};
+
+	match resolved {
+		Some(i) if i < len => i,
+		#[cfg(feature = "unsafe-unchecked")]
+		_ => unsafe { unreachable_unchecked() },
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		_ => panic_bounds_check(idx, len),
+	}
+}
+
+// Resolves an index given as a sign and a magnitude, where `from_end` means that `1` is the last element.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn sign_magnitude_or_panic(from_end: bool, magnitude: usize, len: usize) -> usize {
+	let resolved = if from_end {
+		len.checked_sub(magnitude)
+	} else {
+		Some(magnitude)
+	};
+
+	match resolved {
+		Some(i) if i < len => i,
+		#[cfg(feature = "unsafe-unchecked")]
+		_ => unsafe { unreachable_unchecked() },
+		// The magnitude might not fit in an `isize`, so it's negated as an `i128` instead.
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		_ if from_end => panic_bounds_check(-(magnitude as i128), len),
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		_ => panic_bounds_check(magnitude, len),
+	}
+}
+
+// Resolves a row and a column of a grid with `cols` columns, panicking if either is out of bounds.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn cell_or_panic(row: impl ToIndex, col: impl ToIndex, rows: usize, cols: usize) -> (usize, usize) {
+	match (check_index(row, rows), check_index(col, cols)) {
+		(Some(r), Some(c)) => (r, c),
+		#[cfg(feature = "unsafe-unchecked")]
+		_ => unsafe { unreachable_unchecked() },
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		(None, _) => panic_axis_check("row", row, rows),
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		(_, None) => panic_axis_check("column", col, cols),
+	}
+}
+
+// Resolves a bound (which may be equal to `len`), panicking if it's out of bounds.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn bound_or_panic(idx: impl ToIndex, len: usize) -> usize {
+	match check_bound(idx, len) {
+		Some(i) => i,
+		#[cfg(feature = "unsafe-unchecked")]
+		None => unsafe { unreachable_unchecked() },
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		None => panic_bounds_check(idx, len),
+	}
+}
+
+// An index that has been converted without knowing the length of the sequence it refers to.
+#[derive(Clone, Copy)]
+enum Position {
+	// A position counted from the start.
+	Forward(usize),
+	// A nonzero distance from the end, so that `FromEnd(1)` is the last element.
+	FromEnd(usize),
+}
+
+// Returns `None` if the index fits in neither a `usize` nor an `isize`.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn split_index(idx: impl ToIndex) -> Option<Position> {
+	if let Ok(unsigned_index) = idx.try_into() {
+		Some(Position::Forward(unsigned_index))
+	} else {
+		let signed_index = to_signed(idx)?;
+		Some(Position::FromEnd(signed_index.unsigned_abs()))
+	}
+}
+
+// Converts a forward position into a signed index, counting from the end instead if it doesn't fit.
+// That only happens for slices of zero-sized types longer than `isize::MAX`.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn signed_position(i: usize, len: usize) -> isize {
+	isize::try_from(i).unwrap_or_else(|_| {
+		assert!(i < len, "the position {i} can't be expressed as an isize");
+		// `len - i` is less than `len - isize::MAX`, which is at most `isize::MAX + 1`.
+		0isize.wrapping_sub_unsigned(len - i)
+	})
+}
+
+// Returns the range of indices within `radius` of `i`, clipped to `0..len`. Requires `i < len`.
+// This saturates, so that a huge radius just covers the rest of the slice.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn window_range(i: usize, radius: usize, len: usize) -> Range<usize> {
+	let end = i.saturating_add(radius).saturating_add(1).min(len);
+	i.saturating_sub(radius)..end
+}
+
+// Moves `i` by `offset` positions, wrapping around modulo `len`.
+// Requires `i < len`, which also means `len` is nonzero. This never overflows, even for huge ZST slices.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn wrap_offset(i: usize, offset: isize, len: usize) -> usize {
+	let step = offset.unsigned_abs() % len;
+	if offset >= 0 {
+		let room = len - i;
+		if step < room { i + step } else { step - room }
+	} else if step <= i {
+		i - step
+	} else {
+		len - (step - i)
+	}
+}
+
+/// How an index was resolved, as returned by [`At::explain_at`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Resolution {
+	/// The index converted into a `usize` directly, and refers to the contained position.
+	DirectUsize(usize),
+	/// The index was negative, so it was resolved by counting `offset` elements back from the end.
+	FromEnd {
+		/// The distance from the end, so that `-1` has an offset of `1`.
+		offset: usize,
+		/// The position that the index refers to.
+		resolved: usize,
+	},
+	/// The index doesn't refer to an element, either because it's too large or too small for this slice
+	/// or because it fits in neither a `usize` nor an `isize`.
+	OutOfBounds,
+}
+
+// An iterator over the elements of a slice before and after a skipped element.
+type SkipOne<'a, T> = Chain<Iter<'a, T>, Iter<'a, T>>;
+
+/// How an index relates to a slice, as returned by [`At::classify_at`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndexOutcome {
+	/// The index refers to the element at the contained position.
+	InBounds(usize),
+	/// The index fits in a `usize` or an `isize`, but doesn't refer to an element of this slice.
+	OutOfBounds,
+	/// The index fits in neither a `usize` nor an `isize`, so it can't refer to an element of any slice.
+	Unrepresentable,
+}
+
+/// The number of elements reserved at either end of a slice, as used by [`At::content_at`].
+/// Reserved elements hold something other than content, such as a header or a trailing sentinel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReservedEnds {
+	/// The number of reserved elements at the start.
+	pub leading: usize,
+	/// The number of reserved elements at the end.
+	pub trailing: usize,
+}
+
+impl ReservedEnds {
+	/// Reserve `leading` elements at the start and `trailing` elements at the end.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub const fn new(leading: usize, trailing: usize) -> Self {
+		Self { leading, trailing }
+	}
+
+	/// Reserve `n` elements at the start only.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub const fn leading(n: usize) -> Self {
+		Self::new(n, 0)
+	}
+
+	/// Reserve `n` elements at the end only.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub const fn trailing(n: usize) -> Self {
+		Self::new(0, n)
+	}
+}
+
+/// Whether an index refers to the first or last element of a slice, as returned by [`At::at_flagged`].
+/// In a slice with a single element, both flags are set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EndFlags {
+	/// Whether the element is the first one.
+	pub is_first: bool,
+	/// Whether the element is the last one.
+	pub is_last: bool,
+}
+
+/// Resolve an index against a length, without accessing anything. This is the resolution used
+/// by every method of this crate, so it can be used to check their behavior in property tests.
+///
+/// The result satisfies the following invariants:
+/// - A non-negative index `i` resolves to `Some(i)` if `i < len` and to `None` otherwise
+/// - A negative index `-k` resolves to `Some(len - k)` if `k <= len` and to `None` otherwise
+/// - An index that fits in neither a `usize` nor an `isize` always resolves to `None`
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// assert_eq!(at::resolve(1, 3), Some(1));
+/// assert_eq!(at::resolve(-1, 3), Some(2));
+/// assert_eq!(at::resolve(3, 3), None);
+/// assert_eq!(at::resolve(-4, 3), None);
+/// # }
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+#[must_use]
+pub fn resolve(idx: impl ToIndex, len: usize) -> Option<usize> {
+	check_index(idx, len)
+}
+
+/// Resolve a range against a length, returning the range of resolved indices.
+/// Unlike [`SignedRange::resolve`], an invalid or reversed range produces an empty range rather than `None`,
+/// so the result can be iterated directly.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// assert!(at::resolve_range(-3.., 5).eq([2, 3, 4]));
+/// assert!(at::resolve_range(3..1, 5).eq([]));
+/// # }
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+#[must_use]
+pub fn resolve_range(range: impl Into<SignedRange>, len: usize) -> Range<usize> {
+	range.into().resolve(len).unwrap_or(0..0)
+}
+
+/// Resolve an index against a length like [`resolve`], then narrow the result to a `u32`.
+/// Returns `None` if the index is out of bounds or if the resolved index doesn't fit in a `u32`.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// assert_eq!(at::resolve_u32(-1, 3), Some(2));
+/// assert_eq!(at::resolve_u32(3, 3), None);
+/// # }
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+#[must_use]
+pub fn resolve_u32(idx: impl ToIndex, len: usize) -> Option<u32> {
+	resolve(idx, len)?.try_into().ok()
+}
+
+/// Fill a slice by repeating another one, so that `dst[i]` is `src[i % src.len()]`.
+///
+/// # Panics
+/// Panics if `src` is empty but `dst` isn't.
+///
+/// # Examples
+/// ```
+/// let mut buf = [0; 7];
+/// at::repeat_into(&[1, 2, 3], &mut buf);
+/// assert_eq!(buf, [1, 2, 3, 1, 2, 3, 1]);
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn repeat_into<T: Copy>(src: &[T], dst: &mut [T]) {
+	if dst.is_empty() {
+		return;
+	}
+	assert!(!src.is_empty(), "can't repeat an empty slice");
+	for chunk in dst.chunks_mut(src.len()) {
+		chunk.copy_from_slice(&src[..chunk.len()]);
+	}
+}
+
+/// Access a particular index of a slice of a `Copy` type. Panics if the index is out of bounds.
+///
+/// This is the same as [`At::at`], but takes the slice directly. This is useful when `as_ref()`
+/// is expensive, since the slice can be obtained once and then indexed many times.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// let a = [1, 2, 3];
+///
+/// assert_eq!(at::at_slice(&a, -1), 3);
+/// # }
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn at_slice<T: Copy>(slice: &[T], idx: impl ToIndex) -> T {
+	slice[index_or_panic(idx, slice.len())]
+}
+
+/// Access a particular index of a slice by reference. Panics if the index is out of bounds.
+/// This is the same as [`At::ref_at`], but takes the slice directly, see [`at_slice`].
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// let a = [1, 2, 3];
+///
+/// assert_eq!(at::ref_at_slice(&a, -1), &3);
+/// # }
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn ref_at_slice<T>(slice: &[T], idx: impl ToIndex) -> &T {
+	&slice[index_or_panic(idx, slice.len())]
+}
+
+/// Access a particular index of a slice by mutable reference. Panics if the index is out of bounds.
+/// This is the same as [`At::mut_at`], but takes the slice directly, see [`at_slice`].
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// let mut a = [1, 2, 3];
+///
+/// *at::mut_at_slice(&mut a, -1) += 1;
+/// assert_eq!(a, [1, 2, 4]);
+/// # }
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn mut_at_slice<T>(slice: &mut [T], idx: impl ToIndex) -> &mut T {
+	let len = slice.len();
+
+	&mut slice[index_or_panic(idx, len)]
+}
+
 /// This trait provides the `at`, `ref_at`, and `mut_at` methods for slices
 /// as well as any type that can be deferenced to a slice.
 pub trait At {
@@ -72,122 +665,5579 @@ pub trait At {
 	///
 	/// # Examples
 	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
 	/// use at::At;
 	/// let a = [1, 2, 3];
 	///
 	/// assert_eq!(a.at(2), 3);
 	/// assert_eq!(a.at(-2), 2);
+	///
+	/// // Any `Copy` element type works, not just integers.
+	/// let s: &[char] = &['a', 'b', 'c'];
+	/// assert_eq!(s.at(-1), 'c');
+	/// # }
+	/// ```
+	///
+	/// Elements that aren't `Copy`, such as boxed trait objects, are rejected at compile time.
+	/// Use [`At::ref_at`] for those instead.
+	/// ```compile_fail
+	/// use at::At;
+	/// let handlers: [Box<dyn Fn() -> i32>; 1] = [Box::new(|| 1)];
+	///
+	/// let handler = handlers.at(-1);
 	/// ```
-	#[inline(always)]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
 	fn at<T>(&self, idx: impl ToIndex) -> T
 	where
 		Self: AsRef<[T]>,
 		T: Copy,
 	{
-		let slice = self.as_ref();
-		let len = slice.len();
-
-		match check_index(idx, len) {
-			Some(i) => slice[i],
-			#[cfg(feature = "unsafe-unchecked")]
-			None => unsafe { unreachable_unchecked() },
-			#[cfg(not(feature = "unsafe-unchecked"))]
-			None => panic_bounds_check(idx, len),
-		}
+		at_slice(self.as_ref(), idx)
 	}
 
 	/// Access a particular index by reference. Panics if the index is out of bounds.
+	/// Unlike [`At::at`], this works for any element type, including ones that aren't `Copy`.
 	///
 	/// # Examples
 	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
 	/// use at::At;
 	/// let a = [1, 2, 3];
 	///
 	/// assert_eq!(a.ref_at(2), &3);
 	/// assert_eq!(a.ref_at(-2), &2);
+	///
+	/// // Boxed trait objects work too, for example in a registry of handlers.
+	/// let handlers: Vec<Box<dyn Fn() -> i32>> = vec![Box::new(|| 1), Box::new(|| 2)];
+	/// assert_eq!(handlers.ref_at(-1)(), 2);
+	/// # }
 	/// ```
-	#[inline(always)]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
 	fn ref_at<T>(&self, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		ref_at_slice(self.as_ref(), idx)
+	}
+
+	/// Check that a particular index is in bounds once, returning a [`Validated`] token that can then
+	/// access the element repeatedly without further bounds checks. Returns `None` if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.validate(-1).map(|v| v.get()), Some(&3));
+	/// assert!(a.validate(3).is_none());
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn validate<T>(&self, idx: impl ToIndex) -> Option<Validated<'_, T>>
 	where
 		Self: AsRef<[T]>,
 	{
 		let slice = self.as_ref();
-		let len = slice.len();
+		Validated::new(slice, check_index(idx, slice.len())?)
+	}
 
-		match check_index(idx, len) {
-			Some(i) => &slice[i],
-			#[cfg(feature = "unsafe-unchecked")]
-			None => unsafe { unreachable_unchecked() },
-			#[cfg(not(feature = "unsafe-unchecked"))]
-			None => panic_bounds_check(idx, len),
-		}
+	/// Access a particular index of a `Copy` type, or return `None` if the index is out of bounds.
+	///
+	/// # Examples
+	/// The fallible accessors compose with `?` in functions that return an `Option`:
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	///
+	/// fn ends_sum(v: &[u8]) -> Option<u8> {
+	///     Some(v.try_at(-1)? + v.try_at(0)?)
+	/// }
+	///
+	/// assert_eq!(ends_sum(&[1, 2, 3]), Some(4));
+	/// assert_eq!(ends_sum(&[]), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_at<T>(&self, idx: impl ToIndex) -> Option<T>
+	where
+		Self: AsRef<[T]>,
+		T: Copy,
+	{
+		self.try_ref_at(idx).copied()
 	}
 
-	/// Access a particular index by mutable reference. Panics if the index is out of bounds.
+	/// Access a particular index by reference, or return `None` if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.try_ref_at(-1), Some(&3));
+	/// assert_eq!(a.try_ref_at(-4), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_ref_at<T>(&self, idx: impl ToIndex) -> Option<&T>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		check_index(idx, slice.len()).map(|i| &slice[i])
+	}
+
+	/// Access a particular index by mutable reference, or return `None` if the index is out of bounds.
 	///
 	/// # Examples
 	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
 	/// use at::At;
 	/// let mut a = [1, 2, 3];
 	///
-	/// assert_eq!(a.mut_at(2), &mut 3);
-	/// assert_eq!(a.mut_at(-2), &mut 2);
+	/// assert_eq!(a.try_mut_at(-1), Some(&mut 3));
+	/// assert_eq!(a.try_mut_at(3), None);
+	/// # }
 	/// ```
-	#[inline(always)]
-	fn mut_at<T>(&mut self, idx: impl ToIndex) -> &mut T
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_mut_at<T>(&mut self, idx: impl ToIndex) -> Option<&mut T>
 	where
 		Self: AsMut<[T]>,
 	{
 		let slice = self.as_mut();
-		let len = slice.len();
-
-		match check_index(idx, len) {
-			Some(i) => &mut slice[i],
-			#[cfg(feature = "unsafe-unchecked")]
-			None => unsafe { unreachable_unchecked() },
-			#[cfg(not(feature = "unsafe-unchecked"))]
-			None => panic_bounds_check(idx, len),
-		}
+		check_index(idx, slice.len()).map(|i| &mut slice[i])
 	}
-}
-
-impl<T> At for T {}
-
-mod test {
-	#[cfg(test)]
-	use crate::At;
 
-	#[test]
-	fn test_positive() {
-		extern crate std;
-		use std::vec;
+	/// Access a particular index by reference, returning a [`MustUse`] guard that panics in debug builds
+	/// if it's dropped without being read. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// let last = a.ref_at_must_use(-1);
+	/// assert_eq!(*last, 3);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn ref_at_must_use<T>(&self, idx: impl ToIndex) -> MustUse<'_, T>
+	where
+		Self: AsRef<[T]>,
+	{
+		MustUse::new(self.ref_at(idx))
+	}
 
-		let mut v = vec![1, 2, 3];
-		assert_eq!(v.at(0u8), 1);
-		assert_eq!(v.ref_at(1i128), &2);
-		assert_eq!(v.mut_at(2isize), &mut 3);
+	/// Access a particular index of a slice of `Option`s, flattening the result.
+	/// Returns `None` if the index is out of bounds or if the element is `None`.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let sparse = [Some(1), None, Some(3)];
+	///
+	/// assert_eq!(sparse.flat_at(-1), Some(&3));
+	/// assert_eq!(sparse.flat_at(-2), None);
+	/// assert_eq!(sparse.flat_at(3), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn flat_at<T>(&self, idx: impl ToIndex) -> Option<&T>
+	where
+		Self: AsRef<[Option<T>]>,
+	{
+		self.try_ref_at(idx)?.as_ref()
 	}
 
-	#[test]
-	fn test_negative() {
-		let mut v = [4, 5, 6];
-		assert_eq!(v.at(-1i8), 6);
-		assert_eq!(v.ref_at(-2i128), &5);
-		assert_eq!(v.mut_at(-3isize), &mut 4);
+	/// Access a particular index by reference, or return `default` as an owned value if the index
+	/// is out of bounds. Unlike cloning the element, this doesn't cost anything in the in-bounds case.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, MaybeOwned};
+	/// let names = [String::from("a"), String::from("b")];
+	///
+	/// assert_eq!(names.at_or_owned(-1, String::new()), MaybeOwned::Borrowed(&names[1]));
+	/// assert_eq!(*names.at_or_owned(2, String::from("none")), "none");
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at_or_owned<T>(&self, idx: impl ToIndex, default: T) -> MaybeOwned<'_, T>
+	where
+		Self: AsRef<[T]>,
+	{
+		match self.try_ref_at(idx) {
+			Some(value) => MaybeOwned::Borrowed(value),
+			None => MaybeOwned::Owned(default),
+		}
 	}
 
-	#[test]
-	#[should_panic(expected = "index out of bounds: the len is 1 but the index is -2")]
-	fn test_panic() {
-		let s = ["hi"];
-		let _ = s.at(-2);
+	/// Access a particular index by reference, checking that `invariant` holds for the element in debug builds.
+	/// Panics if the index is out of bounds, or if the invariant doesn't hold and debug assertions are enabled.
+	/// Without debug assertions, `invariant` isn't called at all.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let s = [b'h', b'i', 0];
+	///
+	/// assert_eq!(s.ref_at_checked_with(-1, |&b| b == 0), &0);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn ref_at_checked_with<T>(&self, idx: impl ToIndex, invariant: impl FnOnce(&T) -> bool) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let element = self.ref_at(idx);
+		debug_assert!(
+			invariant(element),
+			"invariant does not hold for the element at index {idx:?}"
+		);
+		element
 	}
 
-	#[test]
-	fn test_zst() {
-		let giant = [(); usize::MAX];
-		giant.at(-1);
-		giant.at(usize::MAX - 1);
-		giant.at(isize::MIN);
+	/// Access a particular index by mutable reference. Panics if the index is out of bounds.
+	///
+	/// This also works on slices of `MaybeUninit`, such as the spare capacity of a `Vec`.
+	/// Writing through the reference doesn't mark the element as initialized;
+	/// keeping track of that (for example, before calling `Vec::set_len`) is up to the caller.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// assert_eq!(a.mut_at(2), &mut 3);
+	/// assert_eq!(a.mut_at(-2), &mut 2);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mut_at<T>(&mut self, idx: impl ToIndex) -> &mut T
+	where
+		Self: AsMut<[T]>,
+	{
+		mut_at_slice(self.as_mut(), idx)
+	}
+
+	/// Access a particular `usize` index by reference. Panics if the index is out of bounds.
+	/// This is like [`ref_at`](At::ref_at), but skips the generic index conversion entirely.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.uat(2), &3);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn uat<T>(&self, idx: usize) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+
+		&slice[uindex_or_panic(idx, slice.len())]
+	}
+
+	/// Access a particular `usize` index of a `Copy` type. Panics if the index is out of bounds.
+	/// This is like [`at`](At::at), but skips the generic index conversion entirely.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.copy_uat(2), 3);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn copy_uat<T>(&self, idx: usize) -> T
+	where
+		Self: AsRef<[T]>,
+		T: Copy,
+	{
+		*self.uat(idx)
+	}
+
+	/// Access a particular `usize` index by mutable reference. Panics if the index is out of bounds.
+	/// This is like [`mut_at`](At::mut_at), but skips the generic index conversion entirely.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// assert_eq!(a.mut_uat(2), &mut 3);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mut_uat<T>(&mut self, idx: usize) -> &mut T
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+
+		&mut slice[uindex_or_panic(idx, len)]
+	}
+
+	/// Access a particular `isize` index by reference. Panics if the index is out of bounds.
+	/// This is like [`ref_at`](At::ref_at), but skips the generic index conversion entirely.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.iat(-1), &3);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn iat<T>(&self, idx: isize) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+
+		&slice[iindex_or_panic(idx, slice.len())]
+	}
+
+	/// Access a particular `isize` index of a `Copy` type. Panics if the index is out of bounds.
+	/// This is like [`at`](At::at), but skips the generic index conversion entirely.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.copy_iat(-1), 3);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn copy_iat<T>(&self, idx: isize) -> T
+	where
+		Self: AsRef<[T]>,
+		T: Copy,
+	{
+		*self.iat(idx)
+	}
+
+	/// Access a particular `isize` index by mutable reference. Panics if the index is out of bounds.
+	/// This is like [`mut_at`](At::mut_at), but skips the generic index conversion entirely.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// assert_eq!(a.mut_iat(-1), &mut 3);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mut_iat<T>(&mut self, idx: isize) -> &mut T
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+
+		&mut slice[iindex_or_panic(idx, len)]
+	}
+
+	/// Access a particular index of a `Copy` type, where the index is anything that converts into a `usize`,
+	/// such as an enum with an `Into<usize>` impl. Negative indices don't apply, so this uses the same
+	/// path as [`uat`](At::uat). Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	///
+	/// enum Reg { A, B, C }
+	/// impl From<Reg> for usize {
+	///     fn from(reg: Reg) -> usize {
+	///         reg as usize
+	///     }
+	/// }
+	///
+	/// let regs = [10, 20, 30];
+	/// assert_eq!(regs.at_by(Reg::C), 30);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at_by<T>(&self, idx: impl Into<usize>) -> T
+	where
+		Self: AsRef<[T]>,
+		T: Copy,
+	{
+		*self.ref_at_by(idx)
+	}
+
+	/// Access a particular index by reference, where the index is anything that converts into a `usize`.
+	/// See [`at_by`](At::at_by). Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.ref_at_by(2u8), &3);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn ref_at_by<T>(&self, idx: impl Into<usize>) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		self.uat(idx.into())
+	}
+
+	/// Access a particular index by mutable reference, where the index is anything that converts into a `usize`.
+	/// See [`at_by`](At::at_by). Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// *a.mut_at_by(0u8) = 0;
+	/// assert_eq!(a, [0, 2, 3]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mut_at_by<T>(&mut self, idx: impl Into<usize>) -> &mut T
+	where
+		Self: AsMut<[T]>,
+	{
+		self.mut_uat(idx.into())
+	}
+
+	/// Access a particular index by reference, given as a sign and a magnitude.
+	/// If `from_end` is `true`, the magnitude counts from the end, so that `1` is the last element;
+	/// otherwise, it counts from the start, so that `0` is the first element.
+	/// This avoids having to construct a negative index (which might overflow). Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.signed_at(true, 1), &3);
+	/// assert_eq!(a.signed_at(false, 1), &2);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn signed_at<T>(&self, from_end: bool, magnitude: usize) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+
+		&slice[sign_magnitude_or_panic(from_end, magnitude, slice.len())]
+	}
+
+	/// Access a particular index of a `Copy` type, given as a sign and a magnitude.
+	/// See [`signed_at`](At::signed_at) for how the index is interpreted. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.copy_signed_at(true, 3), 1);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn copy_signed_at<T>(&self, from_end: bool, magnitude: usize) -> T
+	where
+		Self: AsRef<[T]>,
+		T: Copy,
+	{
+		*self.signed_at(from_end, magnitude)
+	}
+
+	/// Access a particular index by mutable reference, given as a sign and a magnitude.
+	/// See [`signed_at`](At::signed_at) for how the index is interpreted. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// *a.mut_signed_at(true, 1) += 1;
+	/// assert_eq!(a, [1, 2, 4]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mut_signed_at<T>(&mut self, from_end: bool, magnitude: usize) -> &mut T
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+
+		&mut slice[sign_magnitude_or_panic(from_end, magnitude, len)]
+	}
+
+	/// Return a pointer to the element at a particular index. Panics if the index is out of bounds.
+	///
+	/// The pointer is derived from a shared reference, so it must not be used for writing.
+	/// It's only valid for as long as the slice isn't moved, mutated or freed.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// let ptr = a.ptr_at(-1);
+	/// assert_eq!(unsafe { *ptr.as_ptr() }, 3);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn ptr_at<T>(&self, idx: impl ToIndex) -> NonNull<T>
+	where
+		Self: AsRef<[T]>,
+	{
+		NonNull::from(self.ref_at(idx))
+	}
+
+	/// Return a pointer to the element at a particular index that may be used for writing.
+	/// Panics if the index is out of bounds.
+	///
+	/// The pointer is derived from a mutable reference, so it may be used for both reading and writing.
+	/// It's only valid for as long as the slice isn't moved or freed, and isn't accessed through anything else.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// let ptr = a.ptr_mut_at(-1);
+	/// unsafe { *ptr.as_ptr() = 4 };
+	/// assert_eq!(a, [1, 2, 4]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn ptr_mut_at<T>(&mut self, idx: impl ToIndex) -> NonNull<T>
+	where
+		Self: AsMut<[T]>,
+	{
+		NonNull::from(self.mut_at(idx))
+	}
+
+	/// Determine whether an index is in bounds without accessing the element.
+	/// Unlike a plain bounds check, this distinguishes indices that are merely out of bounds
+	/// from indices that are too large to be valid for any slice.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, IndexOutcome};
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.classify_at(-1), IndexOutcome::InBounds(2));
+	/// assert_eq!(a.classify_at(3), IndexOutcome::OutOfBounds);
+	/// assert_eq!(a.classify_at(u128::MAX), IndexOutcome::Unrepresentable);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn classify_at<T>(&self, idx: impl ToIndex) -> IndexOutcome
+	where
+		Self: AsRef<[T]>,
+	{
+		let len = self.as_ref().len();
+
+		match resolve_unbounded(idx, len) {
+			Some(i) if i < len => IndexOutcome::InBounds(i),
+			Some(_) => IndexOutcome::OutOfBounds,
+			None => IndexOutcome::Unrepresentable,
+		}
+	}
+
+	/// Explain how an index is resolved, without accessing the element. This is meant for debugging,
+	/// since it shows whether an index was used directly or counted from the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, Resolution};
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.explain_at(1), Resolution::DirectUsize(1));
+	/// assert_eq!(a.explain_at(-1), Resolution::FromEnd { offset: 1, resolved: 2 });
+	/// assert_eq!(a.explain_at(-4), Resolution::OutOfBounds);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn explain_at<T>(&self, idx: impl ToIndex) -> Resolution
+	where
+		Self: AsRef<[T]>,
+	{
+		let len = self.as_ref().len();
+
+		match split_index(idx) {
+			Some(Position::Forward(i)) if i < len => Resolution::DirectUsize(i),
+			Some(Position::FromEnd(offset)) if offset <= len => Resolution::FromEnd {
+				offset,
+				resolved: len - offset,
+			},
+			_ => Resolution::OutOfBounds,
+		}
+	}
+
+	/// Resolve an index against the length of the slice without accessing the element.
+	/// This is useful for validating an index once and then using it to access parallel slices.
+	///
+	/// # Errors
+	/// Returns an [`IndexError`] if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, IndexError};
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.resolve_result(-1), Ok(2));
+	/// assert_eq!(a.resolve_result(-4), Err(IndexError { index: -4, len: 3 }));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn resolve_result<T>(&self, idx: impl ToIndex) -> Result<usize, IndexError>
+	where
+		Self: AsRef<[T]>,
+	{
+		let len = self.as_ref().len();
+
+		check_index(idx, len).ok_or_else(|| IndexError::new(idx, len))
+	}
+
+	/// Access a particular index by reference, or return an [`OobContext`] that suggests
+	/// the nearest in-bounds index if it's out of bounds.
+	///
+	/// # Errors
+	/// Returns an [`OobContext`] if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3, 4, 5];
+	///
+	/// assert_eq!(a.at_ctx(-1), Ok(&5));
+	/// let err = a.at_ctx(7).unwrap_err();
+	/// assert_eq!(err.nearest_valid, Some(-1));
+	/// assert_eq!(
+	///     err.to_string(),
+	///     "index out of bounds: the len is 5 but the index is 7; did you mean -1?"
+	/// );
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at_ctx<T>(&self, idx: impl ToIndex) -> Result<&T, OobContext>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		match check_index(idx, slice.len()) {
+			Some(i) => Ok(&slice[i]),
+			None => Err(OobContext::new(idx, slice.len())),
+		}
+	}
+
+	/// Access the element at a fractional position by reference. `0.0` is the first element,
+	/// `1.0` is the last, and `0.5` is the middle one. Negative fractions count from the end,
+	/// so `-0.25` refers to the same element as `0.75`.
+	///
+	/// The position `frac * (len - 1)` is rounded to the nearest index, with ties rounding
+	/// away from zero (towards the end of the slice). Panics if the slice is empty
+	/// or if `frac` is NaN or outside of `-1.0..=1.0`.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3, 4, 5];
+	///
+	/// assert_eq!(a.at_frac(0.0), &1);
+	/// assert_eq!(a.at_frac(0.5), &3);
+	/// assert_eq!(a.at_frac(1.0), &5);
+	/// assert_eq!(a.at_frac(0.3), &2);
+	/// assert_eq!(a.at_frac(-0.25), &4);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[allow(
+		clippy::cast_precision_loss,
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss
+	)]
+	fn at_frac<T>(&self, frac: f64) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		assert!(len != 0, "cannot index an empty slice by fraction");
+
+		let resolved = if frac < 0.0 { frac + 1.0 } else { frac };
+		assert!(
+			(0.0..=1.0).contains(&resolved),
+			"fraction out of range: expected a value in -1.0..=1.0 but got {frac}"
+		);
+
+		// `resolved` is non-negative, so adding 0.5 and truncating rounds half away from zero.
+		// The `min` guards against `len - 1` rounding up when converted to a float.
+		let i = ((resolved * (len - 1) as f64 + 0.5) as usize).min(len - 1);
+		&slice[i]
+	}
+
+	/// Linearly interpolate between elements at a fractional index, so that `2.5` is halfway between
+	/// the elements at indices `2` and `3`. Negative positions count from the end like negative indices,
+	/// so `-1.0` is the last element and `-1.5` is halfway between the last two.
+	/// Panics if the slice is empty, or if `pos` is NaN or doesn't resolve to a position in `0.0..=len - 1`.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [0.0, 10.0, 20.0, 40.0];
+	///
+	/// assert_eq!(a.lerp_at(1.5), 15.0);
+	/// assert_eq!(a.lerp_at(-1.0), 40.0);
+	/// assert_eq!(a.lerp_at(-1.5), 30.0);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[allow(
+		clippy::cast_precision_loss,
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss
+	)]
+	fn lerp_at(&self, pos: f64) -> f64
+	where
+		Self: AsRef<[f64]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		assert!(len != 0, "cannot interpolate an empty slice");
+
+		let last = (len - 1) as f64;
+		let resolved = if pos < 0.0 { pos + len as f64 } else { pos };
+		assert!(
+			(0.0..=last).contains(&resolved),
+			"position out of range: the len is {len} but the position is {pos}"
+		);
+
+		// The `min` guards against `resolved` rounding up past the last index when converted.
+		let lo = (resolved as usize).min(len - 1);
+		let hi = (lo + 1).min(len - 1);
+		let t = resolved - lo as f64;
+		slice[lo] + (slice[hi] - slice[lo]) * t
+	}
+
+	/// Access a subslice by reference. The bounds of the range may be negative, see [`SignedRange`].
+	/// Panics if either bound is out of bounds or if the start is after the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.slice_at(1..-1), &[2, 3]);
+	/// assert_eq!(a.slice_at(1..=-1), &[2, 3, 4]);
+	/// assert_eq!(a.slice_at(-2..), &[3, 4]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn slice_at<T>(&self, range: impl Into<SignedRange>) -> &[T]
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		let range = range.into();
+
+		match range.resolve(len) {
+			Some(r) => &slice[r],
+			#[cfg(feature = "unsafe-unchecked")]
+			None => unsafe { unreachable_unchecked() },
+			#[cfg(not(feature = "unsafe-unchecked"))]
+			None => panic_range_check(range, len),
+		}
+	}
+
+	/// Access a subslice by reference, or return `None` if either bound is out of bounds or if the start
+	/// is after the end. The bounds of the range may be negative, see [`SignedRange`].
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.try_slice_at(-2..), Some(&[3, 4][..]));
+	/// assert_eq!(a.try_slice_at(-5..), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_slice_at<T>(&self, range: impl Into<SignedRange>) -> Option<&[T]>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		Some(&slice[range.into().resolve(slice.len())?])
+	}
+
+	/// Access a subslice by reference, or return a [`RangeError`] saying which part of the range is at fault.
+	/// The bounds of the range may be negative, see [`SignedRange`].
+	///
+	/// # Errors
+	/// Returns a [`RangeError`] if either bound is out of bounds or if the start is after the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, RangeError};
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.slice_at_ctx(1..-1), Ok(&[2, 3][..]));
+	/// assert_eq!(a.slice_at_ctx(1..9), Err(RangeError::EndOutOfRange(9)));
+	/// assert_eq!(a.slice_at_ctx(-1..-2), Err(RangeError::Reversed { start: 3, end: 2 }));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn slice_at_ctx<T>(&self, range: impl Into<SignedRange>) -> Result<&[T], RangeError>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		Ok(&slice[range.into().resolve_or_error(slice.len())?])
+	}
+
+	/// Access a subslice by reference, given a pair of bounds that may be negative, see [`SignedRange`].
+	/// This accepts every shape of range, including ones with an excluded start, which have no literal syntax.
+	/// Panics if either bound is out of bounds or if the start is after the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// use core::ops::Bound::{Excluded, Included, Unbounded};
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.slice_bounds_at(Excluded(0), Included(-1)), &[2, 3, 4]);
+	/// assert_eq!(a.slice_bounds_at(Excluded(-3), Unbounded), &[3, 4]);
+	///
+	/// // A pair of bounds also works with `slice_at` directly.
+	/// assert_eq!(a.slice_at((Excluded(0), Excluded(-1))), &[2, 3]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn slice_bounds_at<T>(&self, start: Bound<isize>, end: Bound<isize>) -> &[T]
+	where
+		Self: AsRef<[T]>,
+	{
+		self.slice_at(SignedRange::new(start, end))
+	}
+
+	/// Join the strings in a subslice with a separator. The bounds of the range may be negative,
+	/// see [`SignedRange`]. Panics if either bound is out of bounds or if the start is after the end,
+	/// just like [`slice_at`](At::slice_at). Requires the `alloc` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let parts = ["usr", "lib", "libc.so"];
+	///
+	/// assert_eq!(parts.join_at(-2.., "/"), "lib/libc.so");
+	/// assert_eq!(parts.join_at(..0, "/"), "");
+	/// # }
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn join_at<T>(&self, range: impl Into<SignedRange>, sep: &str) -> alloc::string::String
+	where
+		Self: AsRef<[T]>,
+		T: AsRef<str>,
+	{
+		let mut joined = alloc::string::String::new();
+		for (i, part) in self.slice_at(range).iter().enumerate() {
+			if i > 0 {
+				joined.push_str(sep);
+			}
+			joined.push_str(part.as_ref());
+		}
+		joined
+	}
+
+	/// Access a subslice by mutable reference. The bounds of the range may be negative, see [`SignedRange`].
+	/// Panics if either bound is out of bounds or if the start is after the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4];
+	///
+	/// a.slice_mut_at(-2..).fill(0);
+	/// assert_eq!(a, [1, 2, 0, 0]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn slice_mut_at<T>(&mut self, range: impl Into<SignedRange>) -> &mut [T]
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+		let range = range.into();
+
+		match range.resolve(len) {
+			Some(r) => &mut slice[r],
+			#[cfg(feature = "unsafe-unchecked")]
+			None => unsafe { unreachable_unchecked() },
+			#[cfg(not(feature = "unsafe-unchecked"))]
+			None => panic_range_check(range, len),
+		}
+	}
+
+	/// Iterate over a subslice by mutable reference. The bounds of the range may be negative, see [`SignedRange`].
+	/// Panics if either bound is out of bounds or if the start is after the end, just like
+	/// [`slice_mut_at`](At::slice_mut_at).
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut buf = [1, 2, 3, 4];
+	///
+	/// buf.iter_mut_at(-3..-1).for_each(|x| *x = 0);
+	/// assert_eq!(buf, [1, 0, 0, 4]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn iter_mut_at<T>(&mut self, range: impl Into<SignedRange>) -> IterMut<'_, T>
+	where
+		Self: AsMut<[T]>,
+	{
+		self.slice_mut_at(range).iter_mut()
+	}
+
+	/// Iterate over `chunk_size` elements at a time, starting at `start` and going towards the front.
+	/// Chunks are aligned to `start` rather than to the beginning of the slice, so only the
+	/// last chunk may be shorter. `start` may be equal to the length of the slice.
+	/// Panics if `start` is out of bounds or if `chunk_size` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [0, 1, 2, 3, 4, 5, 6];
+	///
+	/// let mut chunks = a.rchunks_at(3, -1);
+	/// assert_eq!(chunks.next(), Some(&[3, 4, 5][..]));
+	/// assert_eq!(chunks.next(), Some(&[0, 1, 2][..]));
+	/// assert_eq!(chunks.next(), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rchunks_at<T>(&self, chunk_size: usize, start: impl ToIndex) -> RChunks<'_, T>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		slice[..bound_or_panic(start, slice.len())].rchunks(chunk_size)
+	}
+
+	/// Iterate mutably over `chunk_size` elements at a time, starting at `start` and going towards the front.
+	/// See [`At::rchunks_at`] for details. Panics if `start` is out of bounds or if `chunk_size` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [0, 1, 2, 3, 4, 5, 6];
+	///
+	/// for chunk in a.rchunks_mut_at(2, 5) {
+	///     chunk.reverse();
+	/// }
+	/// assert_eq!(a, [0, 2, 1, 4, 3, 5, 6]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rchunks_mut_at<T>(&mut self, chunk_size: usize, start: impl ToIndex) -> RChunksMut<'_, T>
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let end = bound_or_panic(start, slice.len());
+		slice[..end].rchunks_mut(chunk_size)
+	}
+
+	/// Access the middle element by reference, or `None` if the slice is empty.
+	/// The middle is at index `len / 2` (rounded down), so for an even length
+	/// this is the later of the two central elements.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	///
+	/// assert_eq!([1, 2, 3].mid_at(), Some(&2));
+	/// assert_eq!([1, 2, 3, 4].mid_at(), Some(&3));
+	/// assert_eq!([0; 0].mid_at(), None);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mid_at<T>(&self) -> Option<&T>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		slice.get(slice.len() / 2)
+	}
+
+	/// Access the middle element by mutable reference, or `None` if the slice is empty.
+	/// See [`At::mid_at`] for how the middle is chosen.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4];
+	///
+	/// *a.mid_mut_at().unwrap() = 0;
+	/// assert_eq!(a, [1, 2, 0, 4]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mid_mut_at<T>(&mut self) -> Option<&mut T>
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		slice.get_mut(slice.len() / 2)
+	}
+
+	/// Access the first, middle, and last elements by reference, or `None` if the slice is empty.
+	/// This is useful for median-of-three pivot selection. See [`At::mid_at`] for how the middle
+	/// is chosen. For short slices, some of the returned references point to the same element.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	///
+	/// assert_eq!([5, 1, 4, 2].mid3_at(), Some((&5, &4, &2)));
+	/// assert_eq!([7].mid3_at(), Some((&7, &7, &7)));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mid3_at<T>(&self) -> Option<(&T, &T, &T)>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		Some((slice.first()?, &slice[slice.len() / 2], slice.last()?))
+	}
+
+	/// Access the element `offset` positions away from `idx` by reference, wrapping around at both ends.
+	/// For example, the neighbor after the last element is the first element.
+	/// Panics if `idx` is out of bounds, which includes every index into an empty slice.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.neighbor_at(1, 1), &3);
+	/// assert_eq!(a.neighbor_at(-1, 1), &1);
+	/// assert_eq!(a.neighbor_at(0, -1), &4);
+	/// assert_eq!(a.neighbor_at(0, -9), &4);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn neighbor_at<T>(&self, idx: impl ToIndex, offset: isize) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+
+		&slice[wrap_offset(index_or_panic(idx, len), offset, len)]
+	}
+
+	/// Access a particular index of a `Copy` type, returning an error if the index is out of bounds.
+	/// The error contains the original index with its original type, see [`OutOfBounds`].
+	/// It can be converted into an [`IndexError`] if a single error type is preferred.
+	///
+	/// # Errors
+	/// Returns [`OutOfBounds`] if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, OutOfBounds};
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.try_typed_at(-1), Ok(3));
+	/// assert_eq!(a.try_typed_at(5u128), Err(OutOfBounds { index: 5u128, len: 3 }));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_typed_at<I: ToIndex, T>(&self, idx: I) -> Result<T, OutOfBounds<I>>
+	where
+		Self: AsRef<[T]>,
+		T: Copy,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+
+		match check_index(idx, len) {
+			Some(i) => Ok(slice[i]),
+			None => Err(OutOfBounds { index: idx, len }),
+		}
+	}
+
+	/// Split the slice around a particular index, returning the elements before it,
+	/// the element itself, and the elements after it, all by mutable reference.
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4];
+	///
+	/// let (left, pivot, right) = a.split_around_mut_at(-2);
+	/// assert_eq!((&*left, &*pivot, &*right), (&[1, 2][..], &3, &[4][..]));
+	///
+	/// core::mem::swap(pivot, &mut left[0]);
+	/// right[0] = 0;
+	/// assert_eq!(a, [3, 2, 1, 0]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn split_around_mut_at<T>(&mut self, idx: impl ToIndex) -> (&mut [T], &mut T, &mut [T])
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+
+		let (left, rest) = slice.split_at_mut(index_or_panic(idx, len));
+		let (pivot, right) = rest
+			.split_first_mut()
+			.expect("the pivot index is in bounds");
+		(left, pivot, right)
+	}
+
+	/// Split the slice around a particular index, returning the elements before it and the elements after it.
+	/// The element at the index itself is left out. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.without_at(-2), (&[1, 2][..], &[4][..]));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn without_at<T>(&self, idx: impl ToIndex) -> (&[T], &[T])
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let i = index_or_panic(idx, slice.len());
+
+		(&slice[..i], &slice[i + 1..])
+	}
+
+	/// Iterate over every element except the one at a particular index. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert!(a.iter_without_at(-2).eq(&[1, 2, 4]));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn iter_without_at<T>(&self, idx: impl ToIndex) -> SkipOne<'_, T>
+	where
+		Self: AsRef<[T]>,
+	{
+		let (left, right) = self.without_at(idx);
+		left.iter().chain(right)
+	}
+
+	/// Access a cell of a grid stored as rows of `W` elements by reference. The row is resolved against
+	/// the number of rows and the column against `W`, so `(-1, -1)` is the bottom-right cell.
+	/// Panics if either index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let grid = [[1, 2, 3], [4, 5, 6]];
+	///
+	/// assert_eq!(grid.at2(-1, -1), &6);
+	/// assert_eq!(grid.at2(0, -3), &1);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at2<T, const W: usize>(&self, row: impl ToIndex, col: impl ToIndex) -> &T
+	where
+		Self: AsRef<[[T; W]]>,
+	{
+		let grid = self.as_ref();
+		let (r, c) = cell_or_panic(row, col, grid.len(), W);
+
+		&grid[r][c]
+	}
+
+	/// Access a cell of a grid of a `Copy` type, see [`At::at2`]. Panics if either index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let grid = [[1, 2, 3], [4, 5, 6]];
+	///
+	/// assert_eq!(grid.copy_at2(-2, 1), 2);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn copy_at2<T, const W: usize>(&self, row: impl ToIndex, col: impl ToIndex) -> T
+	where
+		Self: AsRef<[[T; W]]>,
+		T: Copy,
+	{
+		*self.at2(row, col)
+	}
+
+	/// Access a cell of a grid by mutable reference, see [`At::at2`]. Panics if either index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut grid = [[1, 2, 3], [4, 5, 6]];
+	///
+	/// *grid.mut_at2(-1, 0) = 0;
+	/// assert_eq!(grid, [[1, 2, 3], [0, 5, 6]]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mut_at2<T, const W: usize>(&mut self, row: impl ToIndex, col: impl ToIndex) -> &mut T
+	where
+		Self: AsMut<[[T; W]]>,
+	{
+		let grid = self.as_mut();
+		let (r, c) = cell_or_panic(row, col, grid.len(), W);
+
+		&mut grid[r][c]
+	}
+
+	/// Return the chunk number and the offset within that chunk of a particular index, when the slice is
+	/// divided into chunks of `chunk_size` elements. Returns `None` if the index is out of bounds.
+	/// Panics if `chunk_size` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [0; 10];
+	///
+	/// assert_eq!(a.chunk_coords_at(4, -1), Some((2, 1)));
+	/// assert_eq!(a.chunk_coords_at(4, 5), Some((1, 1)));
+	/// assert_eq!(a.chunk_coords_at(4, 10), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn chunk_coords_at<T>(&self, chunk_size: usize, idx: impl ToIndex) -> Option<(usize, usize)>
+	where
+		Self: AsRef<[T]>,
+	{
+		assert!(chunk_size != 0, "chunk size must be non-zero");
+		let i = check_index(idx, self.as_ref().len())?;
+		Some((i / chunk_size, i % chunk_size))
+	}
+
+	/// Access a particular index by reference along with an iterator over every other element, in order.
+	/// Returns `None` if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let record = [3, 10, 20, 30];
+	///
+	/// let (count, rest) = record.pluck_at(0).unwrap();
+	/// assert_eq!(rest.count(), *count);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn pluck_at<T>(&self, idx: impl ToIndex) -> Option<(&T, SkipOne<'_, T>)>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let i = check_index(idx, slice.len())?;
+
+		Some((&slice[i], slice[..i].iter().chain(&slice[i + 1..])))
+	}
+
+	/// Iterate over the elements at each of the given indices, in order. The iterator panics when it
+	/// reaches an index that's out of bounds, and the panic message includes that index.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [10, 20, 30, 40];
+	///
+	/// assert!(a.gather_at(&[-1, 0, 2]).eq(&[40, 10, 30]));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn gather_at<'a, T, I: ToIndex>(&'a self, indices: &'a [I]) -> GatherIter<'a, T, I>
+	where
+		Self: AsRef<[T]>,
+	{
+		GatherIter::new(self.as_ref(), indices)
+	}
+
+	/// Iterate over the elements at each of the given indices, in order,
+	/// yielding `None` for indices that are out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [10, 20, 30, 40];
+	///
+	/// assert!(a.try_gather_at(&[-1, 4]).eq([Some(&40), None]));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_gather_at<'a, T, I: ToIndex>(&'a self, indices: &'a [I]) -> TryGatherIter<'a, T, I>
+	where
+		Self: AsRef<[T]>,
+	{
+		TryGatherIter::new(self.as_ref(), indices)
+	}
+
+	/// Access a particular index by reference, with the lifetime of the borrow as a named parameter.
+	/// Panics if the index is out of bounds.
+	///
+	/// This behaves exactly like [`At::ref_at`]. The difference is that `'g` is an early-bound lifetime,
+	/// so it can be specified explicitly, e.g. `v.ref_at_in::<'a, T>(idx)`. This lets generic code pin
+	/// the borrow to a particular region when inference would otherwise pick a shorter one,
+	/// or spell out the region in a function signature. In most code, plain `ref_at` is all you need.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	///
+	/// fn last_name<'a>(names: &'a [&'static str]) -> &'a &'static str {
+	///     names.ref_at_in::<'a, _>(-1)
+	/// }
+	///
+	/// assert_eq!(last_name(&["ada", "grace"]), &"grace");
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn ref_at_in<'g, T>(&'g self, idx: impl ToIndex) -> &'g T
+	where
+		Self: AsRef<[T]> + 'g,
+	{
+		self.ref_at(idx)
+	}
+
+	/// Load the value of the atomic at a particular index with the given memory ordering.
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// use core::sync::atomic::{AtomicU64, Ordering};
+	/// let a = [AtomicU64::new(1), AtomicU64::new(2)];
+	///
+	/// assert_eq!(a.load_at(-1, Ordering::Relaxed), 2);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn load_at<A: Atomic>(&self, idx: impl ToIndex, order: Ordering) -> A::Value
+	where
+		Self: AsRef<[A]>,
+	{
+		self.ref_at(idx).load(order)
+	}
+
+	/// Store a value into the atomic at a particular index with the given memory ordering.
+	/// This only needs a shared reference since atomics allow shared mutation.
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// use core::sync::atomic::{AtomicBool, Ordering};
+	/// let a = [AtomicBool::new(false), AtomicBool::new(false)];
+	///
+	/// a.store_at(-1, true, Ordering::Release);
+	/// assert!(a[1].load(Ordering::Acquire));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn store_at<A: Atomic>(&self, idx: impl ToIndex, val: A::Value, order: Ordering)
+	where
+		Self: AsRef<[A]>,
+	{
+		self.ref_at(idx).store(val, order);
+	}
+
+	/// Take the element at a particular index, leaving `T::default()` in its place.
+	/// Unlike `Vec::remove`, this gives ownership of the element without changing the length,
+	/// so it also works on fixed-size slices. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [String::from("a"), String::from("b")];
+	///
+	/// assert_eq!(a.take_at(-1), "b");
+	/// assert_eq!(a, ["a", ""]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn take_at<T>(&mut self, idx: impl ToIndex) -> T
+	where
+		Self: AsMut<[T]>,
+		T: Default,
+	{
+		core::mem::take(self.mut_at(idx))
+	}
+
+	/// Access a particular index by reference, resolving negative indices against `logical_len`
+	/// instead of the length of the slice. This is useful for views where only the first
+	/// `logical_len` elements are meaningful, so that `-1` refers to the last meaningful element.
+	/// Panics if the index is out of bounds for `logical_len`, or if it resolves to a position
+	/// that is out of bounds for the slice itself.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let buffer = [1, 2, 3, 0, 0];
+	///
+	/// assert_eq!(buffer.len_override_at(3, -1), &3);
+	/// assert_eq!(buffer.len_override_at(3, 0), &1);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn len_override_at<T>(&self, logical_len: usize, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		self.ref_at(index_or_panic(idx, logical_len))
+	}
+
+	/// Access a particular index by reference, skipping the reserved elements at either end.
+	/// Indices are resolved against the content between the reserved elements, so that `0` is the first
+	/// element after the leading ones and `-1` is the last element before the trailing ones.
+	/// Panics if the index is out of bounds for the content.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, ReservedEnds};
+	/// let buf = [b'h', b'i', 0];
+	///
+	/// assert_eq!(buf.content_at(-1, ReservedEnds::trailing(1)), &b'i');
+	/// assert_eq!(buf.content_at(0, ReservedEnds::leading(1)), &b'i');
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn content_at<T>(&self, idx: impl ToIndex, reserved: ReservedEnds) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let start = reserved.leading.min(slice.len());
+		let end = slice.len().saturating_sub(reserved.trailing).max(start);
+
+		slice[start..end].ref_at(idx)
+	}
+
+	/// Access a particular index by mutable reference, resolving negative indices against `logical_len`
+	/// instead of the length of the slice. See [`At::len_override_at`] for details.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut buffer = [1, 2, 3, 0, 0];
+	///
+	/// *buffer.len_override_mut_at(3, -1) = 9;
+	/// assert_eq!(buffer, [1, 2, 9, 0, 0]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn len_override_mut_at<T>(&mut self, logical_len: usize, idx: impl ToIndex) -> &mut T
+	where
+		Self: AsMut<[T]>,
+	{
+		self.mut_at(index_or_panic(idx, logical_len))
+	}
+
+	/// Access the first `n` elements by reference, or the whole slice if it has fewer than `n` elements.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.first_n(2), &[1, 2]);
+	/// assert_eq!(a.first_n(5), &[1, 2, 3]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn first_n<T>(&self, n: usize) -> &[T]
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		&slice[..n.min(slice.len())]
+	}
+
+	/// Access the first `n` elements by mutable reference, or the whole slice if it has fewer than `n` elements.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// a.first_n_mut(2).fill(0);
+	/// assert_eq!(a, [0, 0, 3]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn first_n_mut<T>(&mut self, n: usize) -> &mut [T]
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let end = n.min(slice.len());
+		&mut slice[..end]
+	}
+
+	/// Access the last `n` elements by reference, or the whole slice if it has fewer than `n` elements.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.last_n(2), &[2, 3]);
+	/// assert_eq!(a.last_n(5), &[1, 2, 3]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn last_n<T>(&self, n: usize) -> &[T]
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		&slice[slice.len().saturating_sub(n)..]
+	}
+
+	/// Access the last `n` elements by mutable reference, or the whole slice if it has fewer than `n` elements.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// a.last_n_mut(2).fill(0);
+	/// assert_eq!(a, [1, 0, 0]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn last_n_mut<T>(&mut self, n: usize) -> &mut [T]
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let start = slice.len().saturating_sub(n);
+		&mut slice[start..]
+	}
+
+	/// Access several distinct indices by mutable reference at once. The references are returned
+	/// in the same order as the indices. Returns `None` if any index is out of bounds, or if
+	/// two indices refer to the same element (for example `0` and `-len`).
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4];
+	///
+	/// let [last, first] = a.gather_mut([-1, 0]).unwrap();
+	/// core::mem::swap(last, first);
+	/// assert_eq!(a, [4, 2, 3, 1]);
+	///
+	/// assert!(a.gather_mut([1, -3]).is_none());
+	/// assert!(a.gather_mut([1, 4]).is_none());
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn gather_mut<T, const N: usize>(&mut self, indices: [impl ToIndex; N]) -> Option<[&mut T; N]>
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+
+		// Pairs each resolved index with its position in `indices`, then visits them in increasing
+		// order, splitting off each element in turn. This also detects repeated indices.
+		let mut resolved = [(0, 0); N];
+		for (k, (pair, idx)) in resolved.iter_mut().zip(indices).enumerate() {
+			*pair = (check_index(idx, len)?, k);
+		}
+		resolved.sort_unstable();
+
+		let mut elements = [const { None }; N];
+		let mut rest = slice;
+		let mut offset = 0;
+		for (i, k) in resolved {
+			let (element, tail) = rest.get_mut(i.checked_sub(offset)?..)?.split_first_mut()?;
+			elements[k] = Some(element);
+			rest = tail;
+			offset = i + 1;
+		}
+		Some(elements.map(|element| element.expect("every position was filled")))
+	}
+
+	/// Access a particular index of a `Copy` type, or compute a fallback with `f` if the index is out of bounds.
+	/// `f` is only called in the out-of-bounds case, like `Option::unwrap_or_else`.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.at_or_else(-1, || 0), 3);
+	/// assert_eq!(a.at_or_else(3, || 0), 0);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at_or_else<T>(&self, idx: impl ToIndex, f: impl FnOnce() -> T) -> T
+	where
+		Self: AsRef<[T]>,
+		T: Copy,
+	{
+		let slice = self.as_ref();
+		check_index(idx, slice.len()).map_or_else(f, |i| slice[i])
+	}
+
+	/// Access a particular index by reference, or compute a fallback reference with `f` if the index is out of bounds.
+	/// `f` is only called in the out-of-bounds case, like `Option::unwrap_or_else`.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [String::from("a"), String::from("b")];
+	/// let fallback = String::from("none");
+	///
+	/// assert_eq!(a.ref_at_or_else(-1, || &fallback), "b");
+	/// assert_eq!(a.ref_at_or_else(-3, || &fallback), "none");
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn ref_at_or_else<'a, T>(&'a self, idx: impl ToIndex, f: impl FnOnce() -> &'a T) -> &'a T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		check_index(idx, slice.len()).map_or_else(f, |i| &slice[i])
+	}
+
+	/// Iterate over overlapping windows of `frame` elements, beginning at `start`
+	/// and advancing by `hop` elements each time. Iteration stops once a full frame no longer fits.
+	/// `start` may be equal to the length of the slice, in which case there are no frames.
+	/// Panics if `start` is out of bounds or if `frame` or `hop` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [0, 1, 2, 3, 4, 5, 6];
+	///
+	/// let frames: Vec<_> = a.frames_at(3, 2, 0).collect();
+	/// assert_eq!(frames, [&[0, 1, 2], &[2, 3, 4], &[4, 5, 6]]);
+	///
+	/// let frames: Vec<_> = a.frames_at(2, 1, -3).collect();
+	/// assert_eq!(frames, [&[4, 5], &[5, 6]]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn frames_at<T>(&self, frame: usize, hop: usize, start: impl ToIndex) -> StepBy<Windows<'_, T>>
+	where
+		Self: AsRef<[T]>,
+	{
+		assert!(frame != 0, "frame size must be non-zero");
+		assert!(hop != 0, "hop size must be non-zero");
+
+		let slice = self.as_ref();
+		slice[bound_or_panic(start, slice.len())..]
+			.windows(frame)
+			.step_by(hop)
+	}
+
+	/// Access a particular index by reference, as if the slice were repeated `repeat` times.
+	/// The index is resolved against the length of the repeated sequence and then mapped back into the slice,
+	/// so with `repeat = 3`, both `len + 2` and `-1` refer to the last element of a three-element slice.
+	/// Panics if the index is out of bounds for the repeated sequence, or if its length overflows a `usize`.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.tiled_at(3, 5), &3);
+	/// assert_eq!(a.tiled_at(3, -9), &1);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn tiled_at<T>(&self, repeat: usize, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		let tiled_len = len
+			.checked_mul(repeat)
+			.expect("tiled length overflows a usize");
+
+		// The resolved index is in bounds for a nonzero tiled length, so `len` is also nonzero.
+		&slice[index_or_panic(idx, tiled_len) % len]
+	}
+
+	/// Access a particular index by reference, using `mode` to decide what happens
+	/// if the index is out of bounds. See [`BorderMode`] for the details of each mode.
+	/// Panics if the slice is empty, regardless of the mode.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, BorderMode};
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.at_with(5, BorderMode::Wrap), &2);
+	/// assert_eq!(a.at_with(5, BorderMode::Clamp), &4);
+	/// assert_eq!(a.at_with(5, BorderMode::Mirror), &2);
+	/// assert_eq!(a.at_with(-5, BorderMode::Wrap), &4);
+	/// assert_eq!(a.at_with(-5, BorderMode::Clamp), &1);
+	/// assert_eq!(a.at_with(-5, BorderMode::Mirror), &2);
+	/// assert_eq!(a.at_with(-1, BorderMode::Panic), &4);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at_with<T>(&self, idx: impl ToIndex, mode: BorderMode) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		&slice[index_with_or_panic(idx, slice.len(), mode)]
+	}
+
+	/// Access a particular index by reference, wrapping around if it's out of bounds.
+	/// This is equivalent to `at_with(idx, BorderMode::Wrap)`. Panics if the slice is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.wrapping_at(3), &1);
+	/// assert_eq!(a.wrapping_at(-4), &3);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn wrapping_at<T>(&self, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		self.at_with(idx, BorderMode::Wrap)
+	}
+
+	/// Access a particular index by reference, clamping it to the first or last element if it's out of bounds.
+	/// This is equivalent to `at_with(idx, BorderMode::Clamp)`. Panics if the slice is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.saturating_at(10), &3);
+	/// assert_eq!(a.saturating_at(-10), &1);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn saturating_at<T>(&self, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		self.at_with(idx, BorderMode::Clamp)
+	}
+
+	/// Access a particular index by reference, clamping it to the first or last element if it's out of bounds,
+	/// even if it fits in neither a `usize` nor an `isize`. Panics if the slice is empty.
+	///
+	/// This differs from [`saturating_at`](At::saturating_at) only for such unrepresentable indices,
+	/// which `saturating_at` rejects since it can't tell where they are. Here, they're clamped
+	/// to the first element if they're negative and to the last element otherwise.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.at_saturating_convert(u128::MAX), &3);
+	/// assert_eq!(a.at_saturating_convert(i128::MIN), &1);
+	/// assert_eq!(a.at_saturating_convert(-2), &2);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at_saturating_convert<T>(&self, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		if split_index(idx).is_some() {
+			return self.saturating_at(idx);
+		}
+
+		let element = if error::saturate_index(idx) < 0 {
+			slice.first()
+		} else {
+			slice.last()
+		};
+		match element {
+			Some(element) => element,
+			#[cfg(feature = "unsafe-unchecked")]
+			None => unsafe { unreachable_unchecked() },
+			#[cfg(not(feature = "unsafe-unchecked"))]
+			None => panic_bounds_check(idx, 0),
+		}
+	}
+
+	/// Access a particular index by reference, clamping it like [`saturating_at`](At::saturating_at),
+	/// and also return whether it had to be clamped. Panics if the slice is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.clamped_at(-1), (false, &3));
+	/// assert_eq!(a.clamped_at(10), (true, &3));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn clamped_at<T>(&self, idx: impl ToIndex) -> (bool, &T)
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		match check_index(idx, slice.len()) {
+			Some(i) => (false, &slice[i]),
+			None => (true, self.saturating_at(idx)),
+		}
+	}
+
+	/// Access a particular index by reference, clamping it like [`saturating_at`](At::saturating_at),
+	/// and also return whether it had to be clamped. Returns `None` if the slice is empty
+	/// or if the index fits in neither a `usize` nor an `isize`.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.try_clamped_at(-10), Some((true, &1)));
+	/// assert_eq!([0; 0].try_clamped_at(0), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_clamped_at<T>(&self, idx: impl ToIndex) -> Option<(bool, &T)>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		match check_index(idx, len) {
+			Some(i) => Some((false, &slice[i])),
+			None => check_index_with(idx, len, BorderMode::Clamp).map(|i| (true, &slice[i])),
+		}
+	}
+
+	/// Access a particular index by reference, reflecting off the ends if it's out of bounds.
+	/// This is equivalent to `at_with(idx, BorderMode::Mirror)`. Panics if the slice is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.mirror_at(3), &2);
+	/// assert_eq!(a.mirror_at(-4), &2);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mirror_at<T>(&self, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		self.at_with(idx, BorderMode::Mirror)
+	}
+
+	/// Return the difference between the element at a particular index and the element before it.
+	/// Negative indices work as usual, so `delta_at(-1)` is the last difference.
+	/// Panics if the index is out of bounds or if it refers to the first element, which has no predecessor.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 4, 9, 16];
+	///
+	/// assert_eq!(a.delta_at(-1), 7);
+	/// assert_eq!(a.delta_at(1), 3);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn delta_at<T>(&self, idx: impl ToIndex) -> T
+	where
+		Self: AsRef<[T]>,
+		T: Copy + Sub<Output = T>,
+	{
+		let slice = self.as_ref();
+		let i = index_or_panic(idx, slice.len());
+		assert!(i > 0, "the element at index {idx:?} has no predecessor");
+
+		slice[i] - slice[i - 1]
+	}
+
+	/// Return the difference between the element at a particular index and the element before it,
+	/// see [`At::delta_at`]. Returns `None` if the index is out of bounds or if it refers to the first element.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 4, 9, 16];
+	///
+	/// assert_eq!(a.try_delta_at(-1), Some(7));
+	/// assert_eq!(a.try_delta_at(-4), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_delta_at<T>(&self, idx: impl ToIndex) -> Option<T>
+	where
+		Self: AsRef<[T]>,
+		T: Copy + Sub<Output = T>,
+	{
+		let slice = self.as_ref();
+		let i = check_index(idx, slice.len())?;
+
+		Some(slice[i] - slice[i.checked_sub(1)?])
+	}
+
+	/// Return the index of the maximum element, or `None` if the slice is empty.
+	/// If several elements are equally maximum, the index of the last one is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [3, 1, 4, 1, 5];
+	///
+	/// assert_eq!(a.argmax_at(), Some(4));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn argmax_at<T>(&self) -> Option<usize>
+	where
+		Self: AsRef<[T]>,
+		T: Ord,
+	{
+		self.argmax_by_key_at(|x| x)
+	}
+
+	/// Return the index of the minimum element, or `None` if the slice is empty.
+	/// If several elements are equally minimum, the index of the first one is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [3, 1, 4, 1, 5];
+	///
+	/// assert_eq!(a.argmin_at(), Some(1));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn argmin_at<T>(&self) -> Option<usize>
+	where
+		Self: AsRef<[T]>,
+		T: Ord,
+	{
+		self.argmin_by_key_at(|x| x)
+	}
+
+	/// Return the index of the element that gives the maximum value of `f`, or `None` if the slice is empty.
+	/// If several elements are equally maximum, the index of the last one is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [-3, 1, -4];
+	///
+	/// assert_eq!(a.argmax_by_key_at(|x: &i32| x.abs()), Some(2));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn argmax_by_key_at<'a, T: 'a, K: Ord>(&'a self, mut f: impl FnMut(&'a T) -> K) -> Option<usize>
+	where
+		Self: AsRef<[T]>,
+	{
+		let (i, _) = self
+			.as_ref()
+			.iter()
+			.enumerate()
+			.max_by_key(|&(_, x)| f(x))?;
+		Some(i)
+	}
+
+	/// Return the index of the element that gives the minimum value of `f`, or `None` if the slice is empty.
+	/// If several elements are equally minimum, the index of the first one is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [-3, 1, -4];
+	///
+	/// assert_eq!(a.argmin_by_key_at(|x: &i32| x.abs()), Some(1));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn argmin_by_key_at<'a, T: 'a, K: Ord>(&'a self, mut f: impl FnMut(&'a T) -> K) -> Option<usize>
+	where
+		Self: AsRef<[T]>,
+	{
+		let (i, _) = self
+			.as_ref()
+			.iter()
+			.enumerate()
+			.min_by_key(|&(_, x)| f(x))?;
+		Some(i)
+	}
+
+	/// Iterate over the indices of the elements that match `pred`, as negative indices from the end,
+	/// starting with the last element. Matches that can't be expressed as an `isize` (only possible
+	/// for slices of zero-sized types) are skipped.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3, 4, 5];
+	///
+	/// let odd: Vec<_> = a.rmatches_at(|x| x % 2 == 1).collect();
+	/// assert_eq!(odd, [-1, -3, -5]);
+	/// assert!(odd.iter().all(|&i| a.ref_at(i) % 2 == 1));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rmatches_at<'a, T: 'a>(
+		&'a self,
+		mut pred: impl FnMut(&T) -> bool,
+	) -> impl Iterator<Item = isize>
+	where
+		Self: AsRef<[T]>,
+	{
+		// Distances from the end are at most `isize::MAX`, so the negated indices fit in an `isize`.
+		self.as_ref()
+			.iter()
+			.rev()
+			.take(isize::MIN.unsigned_abs())
+			.enumerate()
+			.filter(move |&(_, x)| pred(x))
+			.map(|(k, _)| (-1isize).wrapping_sub_unsigned(k))
+	}
+
+	/// Access an element along with its previous and next elements, returned as `(prev, current, next)`.
+	/// `mode` decides which elements are used as neighbors at the ends of the slice, as well as what happens
+	/// if the index itself is out of bounds. See [`BorderMode`] for the details of each mode.
+	/// Panics if the slice is empty, regardless of the mode.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, BorderMode};
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.triplet_at(1, BorderMode::Panic), (&1, &2, &3));
+	/// assert_eq!(a.triplet_at(-1, BorderMode::Wrap), (&3, &4, &1));
+	/// assert_eq!(a.triplet_at(-1, BorderMode::Clamp), (&3, &4, &4));
+	/// assert_eq!(a.triplet_at(-1, BorderMode::Mirror), (&3, &4, &3));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn triplet_at<T>(&self, idx: impl ToIndex, mode: BorderMode) -> (&T, &T, &T)
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		let i = index_with_or_panic(idx, len, mode);
+
+		let prev = position_with_or_panic(i as i128 - 1, len, mode);
+		let next = position_with_or_panic(i as i128 + 1, len, mode);
+		(&slice[prev], &slice[i], &slice[next])
+	}
+
+	/// Create a [`Cursor`] at a particular index, which can then move back and forth over the slice.
+	/// The index may be out of bounds, in which case it's handled using `mode`.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, BorderMode};
+	/// let a = [1, 2, 3];
+	/// let mut cursor = a.cursor_at(-1, BorderMode::Clamp);
+	///
+	/// cursor.advance(5);
+	/// assert_eq!(cursor.current(), Some(&3));
+	/// cursor.seek(0);
+	/// assert_eq!(cursor.peek(-1), Some(&1));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn cursor_at<T>(&self, idx: impl ToIndex, mode: BorderMode) -> Cursor<'_, T>
+	where
+		Self: AsRef<[T]>,
+	{
+		let mut cursor = Cursor::new(self.as_ref(), mode);
+		cursor.seek(idx);
+		cursor
+	}
+
+	/// Access a particular index by reference, using the border mode selected by the type of the index.
+	/// A [`Wrapping`](core::num::Wrapping) index wraps around and a [`Saturating`](core::num::Saturating)
+	/// index is clamped, see [`BorderedIndex`]. Panics if the slice is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// use std::num::{Saturating, Wrapping};
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.bordered_at(Wrapping(4)), &2);
+	/// assert_eq!(a.bordered_at(Saturating(999)), &3);
+	/// assert_eq!(a.bordered_at(Saturating(-999)), &1);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn bordered_at<T>(&self, idx: impl BorderedIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let (idx, mode) = idx.into_parts();
+		self.at_with(idx, mode)
+	}
+
+	/// Read `width` bits starting at a particular bit index, in LSB-first order, so that bit `0` is
+	/// the least significant bit of the first byte. Negative bit indices count from the end as usual,
+	/// so `bits_at(-12, 12)` reads the last 12 bits. Panics if `width` isn't in `1..=64`,
+	/// if the index is out of bounds, or if there aren't enough bits left to read.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let data = [0b1010_0101, 0b0000_1111];
+	///
+	/// assert_eq!(data.bits_at(0, 4), 0b0101);
+	/// assert_eq!(data.bits_at(4, 8), 0b1111_1010);
+	/// assert_eq!(data.bits_at(-12, 12), 0b1111_1010);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn bits_at(&self, bit_idx: impl ToIndex, width: u32) -> u64
+	where
+		Self: AsRef<[u8]>,
+	{
+		read::read_bits_or_panic(self.as_ref(), bit_idx, width)
+	}
+
+	/// Read `width` bits starting at a particular bit index, see [`At::bits_at`].
+	/// Returns `None` if the index is out of bounds or if there aren't enough bits left to read.
+	/// Panics if `width` isn't in `1..=64`.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let data = [0b1010_0101, 0b0000_1111];
+	///
+	/// assert_eq!(data.try_bits_at(-4, 4), Some(0b0000));
+	/// assert_eq!(data.try_bits_at(-4, 5), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_bits_at(&self, bit_idx: impl ToIndex, width: u32) -> Option<u64>
+	where
+		Self: AsRef<[u8]>,
+	{
+		read::read_bits(self.as_ref(), bit_idx, width)
+	}
+
+	/// Reinterpret the bytes as a slice of `U` and access a particular index of it by value.
+	/// Unaligned bytes are fine, since the element is copied out. Panics if the number of bytes isn't
+	/// a multiple of the size of `U`, if `U` is zero-sized, or if the index is out of bounds.
+	/// Requires the `bytemuck` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let bytes = [1, 0, 0, 0, 2, 0, 0, 0];
+	///
+	/// assert_eq!(bytes.cast_at::<u32>(-1), u32::from_ne_bytes([2, 0, 0, 0]));
+	/// # }
+	/// ```
+	#[cfg(feature = "bytemuck")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn cast_at<U>(&self, idx: impl ToIndex) -> U
+	where
+		Self: AsRef<[u8]>,
+		U: bytemuck::AnyBitPattern,
+	{
+		let bytes = self.as_ref();
+		let size = size_of::<U>();
+		assert!(size != 0, "can't index zero-sized elements");
+		assert!(
+			bytes.len() % size == 0,
+			"the len is {} but it must be a multiple of the element size {size}",
+			bytes.len()
+		);
+
+		let i = index_or_panic(idx, bytes.len() / size);
+		bytemuck::pod_read_unaligned(&bytes[i * size..(i + 1) * size])
+	}
+
+	/// Load `LANES` consecutive elements starting at a particular index into a SIMD vector.
+	/// The elements don't need to be aligned. Panics if the index is out of bounds or
+	/// if there aren't enough elements left to load. Requires the `simd` feature (and nightly Rust).
+	///
+	/// # Examples
+	/// ```
+	/// #![feature(portable_simd)]
+	/// use at::At;
+	/// use std::simd::Simd;
+	/// let samples = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+	///
+	/// assert_eq!(samples.simd_at::<4, _>(-4), Simd::from_array([6, 7, 8, 9]));
+	/// ```
+	#[cfg(feature = "simd")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn simd_at<const LANES: usize, T>(&self, idx: impl ToIndex) -> core::simd::Simd<T, LANES>
+	where
+		Self: AsRef<[T]>,
+		T: core::simd::SimdElement,
+	{
+		core::simd::Simd::from_array(read::read_array_or_panic(self.as_ref(), idx, "elements"))
+	}
+
+	read_int_methods! {
+		u16, "little", from_le_bytes, read_u16_le_at, try_read_u16_le_at, "0x0807";
+		u16, "big", from_be_bytes, read_u16_be_at, try_read_u16_be_at, "0x0708";
+		u32, "little", from_le_bytes, read_u32_le_at, try_read_u32_le_at, "0x0807_0605";
+		u32, "big", from_be_bytes, read_u32_be_at, try_read_u32_be_at, "0x0506_0708";
+		u64, "little", from_le_bytes, read_u64_le_at, try_read_u64_le_at, "0x0807_0605_0403_0201";
+		u64, "big", from_be_bytes, read_u64_be_at, try_read_u64_be_at, "0x0102_0304_0506_0708";
+	}
+
+	/// Read a `S` starting at a particular byte index, without any alignment requirement.
+	/// Panics if the index is out of bounds or if there aren't enough bytes left to read.
+	/// Requires the `zerocopy` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let buf = [0xff, 0xff, 7, 1];
+	///
+	/// // Any type implementing `zerocopy::FromBytes` works, including structs deriving it.
+	/// let trailer: [u8; 2] = buf.read_at(-2);
+	/// assert_eq!(trailer, [7, 1]);
+	/// # }
+	/// ```
+	#[cfg(feature = "zerocopy")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn read_at<S: zerocopy::FromBytes>(&self, byte_idx: impl ToIndex) -> S
+	where
+		Self: AsRef<[u8]>,
+	{
+		read::read_from_or_panic(self.as_ref(), byte_idx)
+	}
+
+	/// Read a `S` starting at a particular byte index, without any alignment requirement.
+	/// Returns `None` if the index is out of bounds or if there aren't enough bytes left to read.
+	/// Requires the `zerocopy` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let buf = [1, 2, 3];
+	///
+	/// assert_eq!(buf.try_read_at::<[u8; 2]>(-2), Some([2, 3]));
+	/// assert_eq!(buf.try_read_at::<[u8; 2]>(-1), None);
+	/// # }
+	/// ```
+	#[cfg(feature = "zerocopy")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_read_at<S: zerocopy::FromBytes>(&self, byte_idx: impl ToIndex) -> Option<S>
+	where
+		Self: AsRef<[u8]>,
+	{
+		read::read_from(self.as_ref(), byte_idx)
+	}
+
+	/// Access every `stride`-th element by reference, starting from the first, see [`StridedView`].
+	/// The index is resolved against the number of such elements, so for a matrix stored in row-major
+	/// order with `stride` columns, this accesses the first column. Panics if the index is out of bounds
+	/// or if the stride is zero.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let m = [1, 2, 3, 4, 5, 6];
+	///
+	/// // A 2x3 matrix, whose columns are every third element.
+	/// assert_eq!(m.strided_at(3, -1), &4);
+	/// assert_eq!(m[2..].strided_at(3, -1), &6);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn strided_at<T>(&self, stride: usize, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		StridedView::new(self.as_ref(), 0, stride).ref_at(idx)
+	}
+
+	/// Update each element from the one before it, walking forward from index `1` to the end.
+	/// `f` is called with the previous element, which has already been updated, and the current one.
+	/// Does nothing if the slice has fewer than two elements.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4];
+	///
+	/// // Prefix sums.
+	/// a.rolling_mut_at(|prev, cur| *cur += prev);
+	/// assert_eq!(a, [1, 3, 6, 10]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rolling_mut_at<T>(&mut self, mut f: impl FnMut(&T, &mut T))
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		for i in 1..slice.len() {
+			let (before, rest) = slice.split_at_mut(i);
+			f(&before[i - 1], &mut rest[0]);
+		}
+	}
+
+	/// Update each element from the one after it, walking backward from index `-2` to the start.
+	/// `f` is called with the next element, which has already been updated, and the current one.
+	/// Does nothing if the slice has fewer than two elements.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4];
+	///
+	/// // Suffix sums.
+	/// a.rev_rolling_mut_at(|next, cur| *cur += next);
+	/// assert_eq!(a, [10, 9, 7, 4]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rev_rolling_mut_at<T>(&mut self, mut f: impl FnMut(&T, &mut T))
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		for i in (1..slice.len()).rev() {
+			let (rest, after) = slice.split_at_mut(i);
+			f(&after[0], &mut rest[i - 1]);
+		}
+	}
+
+	/// Compare `n` elements starting at a particular index with `n` elements of another slice starting
+	/// at a particular index. Returns `false` if either run of `n` elements doesn't fit, so this never panics.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = b"abcabc";
+	///
+	/// assert!(a.eq_at(-3, b"xabc", 1, 3));
+	/// assert!(!a.eq_at(-3, b"xabc", 0, 3));
+	/// assert!(!a.eq_at(-2, b"xabc", 1, 3));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn eq_at<T>(
+		&self,
+		self_start: impl ToIndex,
+		other: &[T],
+		other_start: impl ToIndex,
+		n: usize,
+	) -> bool
+	where
+		Self: AsRef<[T]>,
+		T: PartialEq,
+	{
+		let slice = self.as_ref();
+		let (Some(a), Some(b)) = (
+			check_bound(self_start, slice.len()),
+			check_bound(other_start, other.len()),
+		) else {
+			return false;
+		};
+		match (slice[a..].get(..n), other[b..].get(..n)) {
+			(Some(a), Some(b)) => a == b,
+			_ => false,
+		}
+	}
+
+	/// Return the elements within `radius` of a particular index, including the element itself.
+	/// The window is clipped to the slice, so it's smaller near the ends. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let signal = [1, 2, 3, 4, 5, 6];
+	///
+	/// assert_eq!(signal.window_around_at(2, 1), &[2, 3, 4]);
+	/// assert_eq!(signal.window_around_at(-1, 2), &[4, 5, 6]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn window_around_at<T>(&self, idx: impl ToIndex, radius: usize) -> &[T]
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		&slice[window_range(index_or_panic(idx, len), radius, len)]
+	}
+
+	/// Return the elements within `radius` of a particular index by mutable reference, including the element
+	/// itself, see [`window_around_at`](At::window_around_at). The window is clipped to the slice, so it's
+	/// smaller near the ends; the element is at position `radius` in the window, or at its resolved index
+	/// if that's smaller. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut signal = [1, 2, 9, 4, 5];
+	///
+	/// let window = signal.neighborhood_mut_at(-3, 1);
+	/// window[1] = (window[0] + window[2]) / 2;
+	/// assert_eq!(signal, [1, 2, 3, 4, 5]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn neighborhood_mut_at<T>(&mut self, idx: impl ToIndex, radius: usize) -> &mut [T]
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+		&mut slice[window_range(index_or_panic(idx, len), radius, len)]
+	}
+
+	/// Return the slice with the leading run of elements matching the predicate removed.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [0, 0, 1, 0, 2, 0];
+	///
+	/// assert_eq!(a.trim_start_matches_at(|&x| x == 0), &[1, 0, 2, 0]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn trim_start_matches_at<T>(&self, mut pred: impl FnMut(&T) -> bool) -> &[T]
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let start = slice.iter().position(|x| !pred(x)).unwrap_or(slice.len());
+		&slice[start..]
+	}
+
+	/// Return the slice with the trailing run of elements matching the predicate removed.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [0, 0, 1, 0, 2, 0];
+	///
+	/// assert_eq!(a.trim_end_matches_at(|&x| x == 0), &[0, 0, 1, 0, 2]);
+	/// assert_eq!(a.trim_end_matches_at(|_| true), &[]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn trim_end_matches_at<T>(&self, mut pred: impl FnMut(&T) -> bool) -> &[T]
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let end = slice.iter().rposition(|x| !pred(x)).map_or(0, |i| i + 1);
+		&slice[..end]
+	}
+
+	/// Return the slice with the leading and trailing runs of elements matching the predicate removed.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [0, 0, 1, 0, 2, 0];
+	///
+	/// assert_eq!(a.trim_matches_at(|&x| x == 0), &[1, 0, 2]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn trim_matches_at<T>(&self, mut pred: impl FnMut(&T) -> bool) -> &[T]
+	where
+		Self: AsRef<[T]>,
+	{
+		self.trim_start_matches_at(&mut pred)
+			.trim_end_matches_at(pred)
+	}
+
+	/// Iterate over each element paired with its successor, wrapping around so that the last element
+	/// is paired with the first, like the edges of a polygon. An empty slice yields nothing, and a
+	/// slice with a single element yields that element paired with itself.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let triangle = ['a', 'b', 'c'];
+	///
+	/// let edges: Vec<_> = triangle.cycle_pairs_at().collect();
+	/// assert_eq!(edges, [(&'a', &'b'), (&'b', &'c'), (&'c', &'a')]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn cycle_pairs_at<'a, T: 'a>(&'a self) -> impl Iterator<Item = (&'a T, &'a T)>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		slice.iter().zip(slice.iter().cycle().skip(1))
+	}
+
+	/// Access a particular index by reference, and also return whether it's the first or last element.
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, EndFlags};
+	/// let items = ["a", "b", "c"];
+	///
+	/// let mut list = String::new();
+	/// for i in 0..items.len() {
+	///     let (item, flags) = items.at_flagged(i);
+	///     list.push_str(item);
+	///     if !flags.is_last {
+	///         list.push_str(", ");
+	///     }
+	/// }
+	/// assert_eq!(list, "a, b, c");
+	/// assert_eq!(items.at_flagged(-3).1, EndFlags { is_first: true, is_last: false });
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at_flagged<T>(&self, idx: impl ToIndex) -> (&T, EndFlags)
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let i = index_or_panic(idx, slice.len());
+		let flags = EndFlags {
+			is_first: i == 0,
+			is_last: i == slice.len() - 1,
+		};
+		(&slice[i], flags)
+	}
+
+	/// Return a view of the slice in reverse order, see [`RevSlice`].
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.rev_view().ref_at(0), &3);
+	/// assert_eq!(a.rev_view().try_ref_at(3), None);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rev_view<T>(&self) -> RevSlice<'_, T>
+	where
+		Self: AsRef<[T]>,
+	{
+		RevSlice::new(self.as_ref())
+	}
+
+	/// Iterate over the maximal runs of consecutive equal elements, as subslices.
+	/// An empty slice yields nothing.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 1, 2, 3, 3, 3];
+	///
+	/// let mut runs = a.runs_at();
+	/// assert_eq!(runs.next(), Some(&[1, 1][..]));
+	/// assert_eq!(runs.next(), Some(&[2][..]));
+	/// assert_eq!(runs.next().map(|run| run.len()), Some(3));
+	/// assert_eq!(runs.next(), None);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn runs_at<'a, T: PartialEq + 'a>(&'a self) -> impl Iterator<Item = &'a [T]>
+	where
+		Self: AsRef<[T]>,
+	{
+		self.as_ref().chunk_by(|a, b| a == b)
+	}
+
+	/// Iterate over the maximal runs of consecutive elements with equal keys, as subslices.
+	/// An empty slice yields nothing.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 3, 2, 4, 5];
+	///
+	/// let runs: Vec<_> = a.runs_by_key_at(|x| x % 2).collect();
+	/// assert_eq!(runs, [&[1, 3][..], &[2, 4], &[5]]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn runs_by_key_at<'a, T: 'a, K: PartialEq>(
+		&'a self,
+		mut key: impl FnMut(&T) -> K,
+	) -> impl Iterator<Item = &'a [T]>
+	where
+		Self: AsRef<[T]>,
+	{
+		self.as_ref().chunk_by(move |a, b| key(a) == key(b))
+	}
+
+	/// Access the elements at fixed offsets from a particular index by reference, such as the taps of
+	/// a convolution kernel. The center is resolved first, and then each offset is added to it, so they
+	/// don't wrap around. Panics if the center or any of the offset positions are out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let signal = [1, 2, 3, 4, 5];
+	///
+	/// assert_eq!(signal.stencil_at(-2, [-1, 0, 1]), [&3, &4, &5]);
+	/// let [l, c, r] = signal.stencil_at(1, [-1, 0, 1]);
+	/// assert_eq!(l + 2 * c + r, 8);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn stencil_at<T, const N: usize>(&self, center: impl ToIndex, offsets: [isize; N]) -> [&T; N]
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		let c = index_or_panic(center, len) as i128;
+		offsets.map(|offset| {
+			&slice[position_with_or_panic(c + offset as i128, len, BorderMode::Panic)]
+		})
+	}
+
+	/// Rotate the slice so that the element at a particular index becomes the first one, keeping the
+	/// order of the others, like `rotate_left`. Does nothing if the slice is empty, regardless of the index.
+	/// Panics if the index is otherwise out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut cache = ['a', 'b', 'c', 'd'];
+	///
+	/// cache.rotate_to_front_at(-1);
+	/// assert_eq!(cache, ['d', 'a', 'b', 'c']);
+	/// cache.rotate_to_front_at(2);
+	/// assert_eq!(cache, ['b', 'c', 'd', 'a']);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rotate_to_front_at<T>(&mut self, idx: impl ToIndex)
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		if !slice.is_empty() {
+			slice.rotate_left(index_or_panic(idx, slice.len()));
+		}
+	}
+
+	/// Compute the dot product of a subslice with the corresponding prefix of another slice.
+	/// The bounds of the range may be negative, see [`SignedRange`]. Panics if either bound is
+	/// out of bounds, if the start is after the end, or if the other slice is shorter than the range.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let signal = [1, 2, 3, 4];
+	/// let kernel = [10, 1];
+	///
+	/// assert_eq!(signal.dot_at(&kernel, -2..), 34);
+	/// assert_eq!(signal.dot_at(&kernel, ..0), 0);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn dot_at<T>(&self, other: &[T], range: impl Into<SignedRange>) -> T
+	where
+		Self: AsRef<[T]>,
+		T: Copy + Mul<Output = T> + Add<Output = T> + Default,
+	{
+		let window = self.slice_at(range);
+		assert!(
+			other.len() >= window.len(),
+			"the range has {} elements but the other slice only has {}",
+			window.len(),
+			other.len()
+		);
+		window
+			.iter()
+			.zip(other)
+			.fold(T::default(), |acc, (&a, &b)| acc + a * b)
+	}
+
+	/// Access a particular index by reference, holding the last element for non-negative indices
+	/// past the end, like a zero-order hold. Negative indices behave exactly like in [`At::ref_at`].
+	/// Panics if the slice is empty or if a negative index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let samples = [0.0, 0.5, 1.0];
+	///
+	/// assert_eq!(samples.hold_at(1), &0.5);
+	/// assert_eq!(samples.hold_at(100), &1.0);
+	/// assert_eq!(samples.hold_at(-3), &0.0);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn hold_at<T>(&self, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let past_end = match split_index(idx) {
+			Some(Position::Forward(i)) => i >= slice.len(),
+			Some(Position::FromEnd(_)) => false,
+			// Huge indices are past the end too, unless they're negative.
+			None => error::saturate_index(idx) >= 0,
+		};
+		match slice.last() {
+			Some(last) if past_end => last,
+			_ => self.ref_at(idx),
+		}
+	}
+
+	/// Binary search a sorted slice for an element, like `slice::binary_search`, but return the position
+	/// as a signed index that can be passed straight to the other methods. If the element is found,
+	/// returns `Ok` with its index.
+	///
+	/// Positions are forward indices, unless they don't fit in an `isize`, in which case they
+	/// count from the end. That's only possible for slices of zero-sized types.
+	///
+	/// # Errors
+	/// If the element isn't found, returns `Err` with the index where it could be inserted
+	/// to keep the slice sorted, which may be equal to the length.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 3, 5];
+	///
+	/// assert_eq!(a.binary_search_at(&3), Ok(1));
+	/// assert_eq!(a.binary_search_at(&4), Err(2));
+	/// assert_eq!(a.binary_search_at(&6), Err(3));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn binary_search_at<T: Ord>(&self, x: &T) -> Result<isize, isize>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		slice
+			.binary_search(x)
+			.map(|i| signed_position(i, len))
+			.map_err(|i| signed_position(i, len))
+	}
+
+	/// Binary search a slice sorted by a key, like `slice::binary_search_by_key`, but return the position
+	/// as a signed index, see [`At::binary_search_at`].
+	///
+	/// # Errors
+	/// If no element has the key, returns `Err` with the index where one could be inserted
+	/// to keep the slice sorted, which may be equal to the length.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [(1, 'a'), (3, 'b'), (5, 'c')];
+	///
+	/// assert_eq!(a.binary_search_by_key_at(&5, |&(k, _)| k), Ok(2));
+	/// assert_eq!(a.binary_search_by_key_at(&0, |&(k, _)| k), Err(0));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn binary_search_by_key_at<T, B: Ord>(
+		&self,
+		b: &B,
+		f: impl FnMut(&T) -> B,
+	) -> Result<isize, isize>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		slice
+			.binary_search_by_key(b, f)
+			.map(|i| signed_position(i, len))
+			.map_err(|i| signed_position(i, len))
+	}
+
+	/// Swap the first and last elements. Does nothing if the slice has fewer than two elements.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// a.swap_ends();
+	/// assert_eq!(a, [3, 2, 1]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn swap_ends<T>(&mut self)
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		if let Some(last) = slice.len().checked_sub(1) {
+			slice.swap(0, last);
+		}
+	}
+
+	/// Reverse the order of the elements in a subslice, in place. The bounds of the range may be negative,
+	/// see [`SignedRange`]. Panics if either bound is out of bounds or if the start is after the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4, 5];
+	///
+	/// a.reverse_at(1..-1);
+	/// assert_eq!(a, [1, 4, 3, 2, 5]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn reverse_at<T>(&mut self, range: impl Into<SignedRange>)
+	where
+		Self: AsMut<[T]>,
+	{
+		self.slice_mut_at(range).reverse();
+	}
+
+	/// Split the slice into two at a particular index, like `split_at`. The index may be equal to the length,
+	/// in which case the second half is empty. Panics if the index is otherwise out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3, 4];
+	///
+	/// assert_eq!(a.halves_at(-1), (&[1, 2, 3][..], &[4][..]));
+	/// assert_eq!(a.halves_at(4), (&a[..], &[][..]));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn halves_at<T>(&self, at: impl ToIndex) -> (&[T], &[T])
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		slice.split_at(bound_or_panic(at, slice.len()))
+	}
+
+	/// Split the slice into two at a particular index by mutable reference, like `split_at_mut`, see
+	/// [`At::halves_at`]. The halves are disjoint, so they can be handed to separate tasks.
+	/// Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut data = [1, 2, 3, 4];
+	///
+	/// let (prefix, last) = data.halves_mut_at(-1);
+	/// prefix.fill(0);
+	/// last[0] = 5;
+	/// assert_eq!(data, [0, 0, 0, 5]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn halves_mut_at<T>(&mut self, at: impl ToIndex) -> (&mut [T], &mut [T])
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+		slice.split_at_mut(bound_or_panic(at, len))
+	}
+
+	/// Iterate over the elements alternately from the front and the back, converging on the middle:
+	/// first, last, second, second-to-last, and so on. Every element is yielded exactly once,
+	/// so for an odd length the middle element comes last.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3, 4, 5];
+	///
+	/// assert!(a.interleave_ends_at().eq(&[1, 5, 2, 4, 3]));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn interleave_ends_at<'a, T: 'a>(&'a self) -> impl Iterator<Item = &'a T>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let len = slice.len();
+		(0..len).map(move |k| {
+			let i = if k % 2 == 0 { k / 2 } else { len - 1 - k / 2 };
+			&slice[i]
+		})
+	}
+
+	/// Replace each element of a subslice with the running sum of the subslice up to and including it.
+	/// The sum starts from the first element of the subslice, so that element is left unchanged.
+	/// The bounds of the range may be negative, see [`SignedRange`]. Panics if either bound is
+	/// out of bounds or if the start is after the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut buf = [9, 1, 2, 3, 4];
+	///
+	/// buf.cumsum_mut_at(-4..);
+	/// assert_eq!(buf, [9, 1, 3, 6, 10]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn cumsum_mut_at<T>(&mut self, range: impl Into<SignedRange>)
+	where
+		Self: AsMut<[T]>,
+		T: Copy + AddAssign,
+	{
+		self.slice_mut_at(range)
+			.rolling_mut_at(|&prev, cur| *cur += prev);
+	}
+
+	/// Shift all elements towards the front by `by` positions, dropping the first `by` elements
+	/// and filling the vacated positions at the back with `fill`. Unlike `rotate_left`, the dropped
+	/// elements don't wrap around. If `by` is at least the length, the whole slice is filled.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut screen = [1, 2, 3, 4, 5];
+	///
+	/// screen.shift_left_at(2, 0);
+	/// assert_eq!(screen, [3, 4, 5, 0, 0]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn shift_left_at<T>(&mut self, by: usize, fill: T)
+	where
+		Self: AsMut<[T]>,
+		T: Copy,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+		let by = by.min(len);
+		slice.copy_within(by.., 0);
+		slice[len - by..].fill(fill);
+	}
+
+	/// Shift all elements towards the back by `by` positions, dropping the last `by` elements
+	/// and filling the vacated positions at the front with `fill`, see [`At::shift_left_at`].
+	/// If `by` is at least the length, the whole slice is filled.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut screen = [1, 2, 3, 4, 5];
+	///
+	/// screen.shift_right_at(2, 0);
+	/// assert_eq!(screen, [0, 0, 1, 2, 3]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn shift_right_at<T>(&mut self, by: usize, fill: T)
+	where
+		Self: AsMut<[T]>,
+		T: Copy,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+		let by = by.min(len);
+		slice.copy_within(..len - by, by);
+		slice[..by].fill(fill);
+	}
+
+	/// Check that the slice is non-empty once, returning a [`NonEmptySlice`] that can then access
+	/// its first and last elements without further checks. Returns `None` if the slice is empty.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.as_nonempty().map(|s| s.last()), Some(&3));
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn as_nonempty<T>(&self) -> Option<NonEmptySlice<'_, T>>
+	where
+		Self: AsRef<[T]>,
+	{
+		NonEmptySlice::new(self.as_ref())
+	}
+
+	/// Reorder the slice so that the element at a particular index is the one that would be there
+	/// if the slice were sorted, like `nth_element` in C++. Elements before it are less than or equal
+	/// to it and elements after it are greater than or equal to it, in no particular order.
+	/// Negative indices count from the largest element, so `-1` puts the maximum at the end.
+	/// Panics if the index is out of bounds.
+	///
+	/// This uses `select_nth_unstable`, whose worst case is linear.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut data = [5, 1, 4, 2, 3];
+	///
+	/// data.nth_element_at(-1);
+	/// assert_eq!(data.at(-1), 5);
+	/// data.nth_element_at(2);
+	/// assert_eq!(data.at(2), 3);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn nth_element_at<T: Ord>(&mut self, idx: impl ToIndex)
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let i = index_or_panic(idx, slice.len());
+		slice.select_nth_unstable(i);
+	}
+
+	/// Move consecutive repeated elements within a subslice to its end, like the unstable
+	/// `slice::partition_dedup`, and return the number of elements left without repeats. Those are the
+	/// first elements of the subslice, in their original order; the repeats follow in no particular order.
+	/// Elements outside of the subslice aren't touched. The bounds of the range may be negative, see
+	/// [`SignedRange`]. Panics if either bound is out of bounds or if the start is after the end.
+	///
+	/// To remove the repeats from a `Vec` instead, use [`VecAt::dedup_within_at`].
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut a = [1, 1, 2, 2, 3, 3, 3];
+	///
+	/// let n = a.partition_dedup_at(-5..);
+	/// assert_eq!(n, 2);
+	/// assert_eq!(a[..4], [1, 1, 2, 3]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn partition_dedup_at<T: PartialEq>(&mut self, range: impl Into<SignedRange>) -> usize
+	where
+		Self: AsMut<[T]>,
+	{
+		let region = self.slice_mut_at(range);
+		if region.is_empty() {
+			return 0;
+		}
+
+		// `kept` elements have been kept so far, and `region[kept - 1]` is the last of them.
+		let mut kept = 1;
+		for i in 1..region.len() {
+			if region[i] != region[kept - 1] {
+				region.swap(i, kept);
+				kept += 1;
+			}
+		}
+		kept
+	}
+
+	/// Resolve an index to the forward position of the element it refers to, or return `None` if the
+	/// index is out of bounds. This is the same resolution as [`resolve`], so the result is the stable
+	/// forward position of the element no matter how the index was written, which makes it suitable
+	/// for serialization. Without the `no-negative` feature, [`to_from_end`](At::to_from_end) is the inverse.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.canonical_index(-1), Some(2));
+	/// assert_eq!(a.canonical_index(1), Some(1));
+	/// assert_eq!(a.canonical_index(-4), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	fn canonical_index<T>(&self, idx: impl ToIndex) -> Option<usize>
+	where
+		Self: AsRef<[T]>,
+	{
+		check_index(idx, self.as_ref().len())
+	}
+
+	/// Convert a forward position to the equivalent negative index, counting from the end.
+	/// Returns `None` if the position is out of bounds, or if the negative index doesn't fit in an
+	/// `isize` (which only happens for slices of zero-sized types). Not available with the `no-negative` feature.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.to_from_end(2), Some(-1));
+	/// assert_eq!(a.to_from_end(0), Some(-3));
+	/// assert_eq!(a.to_from_end(3), None);
+	/// assert_eq!(a.canonical_index(-2).and_then(|i| a.to_from_end(i)), Some(-2));
+	/// ```
+	#[cfg(not(feature = "no-negative"))]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	fn to_from_end<T>(&self, forward: usize) -> Option<isize>
+	where
+		Self: AsRef<[T]>,
+	{
+		let len = self.as_ref().len();
+		if forward >= len {
+			return None;
+		}
+		0isize.checked_sub_unsigned(len - forward)
+	}
+
+	/// Return the permutation that sorts the slice in ascending order: the first position is that of the
+	/// smallest element, and so on. The sort is stable, so equal elements keep their relative order.
+	/// The positions can be passed to [`ref_at`](At::ref_at), or converted to negative indices with
+	/// [`to_from_end`](At::to_from_end). Requires the `alloc` feature.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [30, 10, 20, 10];
+	///
+	/// let order = a.argsort();
+	/// assert_eq!(order, [1, 3, 2, 0]);
+	/// assert_eq!(a.ref_at(order[0]), &10);
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn argsort<T: Ord>(&self) -> alloc::vec::Vec<usize>
+	where
+		Self: AsRef<[T]>,
+	{
+		self.argsort_by_key(|x| x)
+	}
+
+	/// Return the permutation that sorts the slice in descending order, like [`argsort`](At::argsort).
+	/// The sort is stable, so equal elements keep their relative order rather than being reversed.
+	/// Requires the `alloc` feature.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [30, 10, 20, 10];
+	///
+	/// assert_eq!(a.argsort_desc(), [0, 2, 1, 3]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn argsort_desc<T: Ord>(&self) -> alloc::vec::Vec<usize>
+	where
+		Self: AsRef<[T]>,
+	{
+		self.argsort_by_key(core::cmp::Reverse)
+	}
+
+	/// Return the permutation that sorts the slice by a key in ascending order, like [`argsort`](At::argsort).
+	/// The sort is stable, so elements with equal keys keep their relative order. Requires the `alloc` feature.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let words = ["ccc", "a", "bb", "d"];
+	///
+	/// assert_eq!(words.argsort_by_key(|w| w.len()), [1, 3, 2, 0]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn argsort_by_key<'a, T: 'a, K: Ord>(
+		&'a self,
+		mut key: impl FnMut(&'a T) -> K,
+	) -> alloc::vec::Vec<usize>
+	where
+		Self: AsRef<[T]>,
+	{
+		let slice = self.as_ref();
+		let mut order: alloc::vec::Vec<usize> = (0..slice.len()).collect();
+		order.sort_by_key(|&i| key(&slice[i]));
+		order
+	}
+
+	/// Access a particular index of a `Copy` type, with the bounds check selected at compile time.
+	/// With `CHECKED = true`, this is exactly [`at`](At::at) and panics if the index is out of bounds.
+	/// With `CHECKED = false`, the bounds check is elided and an out-of-bounds index is undefined behavior.
+	///
+	/// This gives per-call-site control over bounds checks, unlike the `unsafe-unchecked` feature,
+	/// which affects the whole program. With that feature, the check is elided either way.
+	///
+	/// # Safety
+	/// This is unsafe to call even with `CHECKED = true`, since unsafety can't depend on a const parameter.
+	/// With `CHECKED = true`, it's sound unless the `unsafe-unchecked` feature is enabled.
+	/// With `CHECKED = false` or that feature, the index must be in bounds.
+	/// With the `no-negative` feature, negative indices are always out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// // SAFETY: the index is in bounds.
+	/// assert_eq!(unsafe { a.at_g::<false, _>(2u8) }, 3);
+	/// // SAFETY: the index is in bounds, which `unsafe-unchecked` requires even when checked.
+	/// assert_eq!(unsafe { a.at_g::<true, _>(0) }, 1);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	unsafe fn at_g<const CHECKED: bool, T>(&self, idx: impl ToIndex) -> T
+	where
+		Self: AsRef<[T]>,
+		T: Copy,
+	{
+		// SAFETY: the caller upholds the same contract.
+		unsafe { *self.ref_at_g::<CHECKED, T>(idx) }
+	}
+
+	/// Access a particular index by reference, with the bounds check selected at compile time,
+	/// like [`at_g`](At::at_g).
+	///
+	/// # Safety
+	/// The same as for [`at_g`](At::at_g): with `CHECKED = false` or the `unsafe-unchecked` feature,
+	/// the index must be in bounds. With the `no-negative` feature, negative indices are always out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// // SAFETY: the index is in bounds.
+	/// assert_eq!(unsafe { a.ref_at_g::<false, _>(1usize) }, &2);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	unsafe fn ref_at_g<const CHECKED: bool, T>(&self, idx: impl ToIndex) -> &T
+	where
+		Self: AsRef<[T]>,
+	{
+		if CHECKED {
+			return self.ref_at(idx);
+		}
+		let slice = self.as_ref();
+		match check_index(idx, slice.len()) {
+			// SAFETY: `check_index` only returns indices that are in bounds.
+			Some(i) => unsafe { slice.get_unchecked(i) },
+			// SAFETY: the caller guarantees that the index is in bounds.
+			None => unsafe { core::hint::unreachable_unchecked() },
+		}
+	}
+
+	/// Access a particular index by mutable reference, with the bounds check selected at compile time,
+	/// like [`at_g`](At::at_g).
+	///
+	/// # Safety
+	/// The same as for [`at_g`](At::at_g): with `CHECKED = false` or the `unsafe-unchecked` feature,
+	/// the index must be in bounds. With the `no-negative` feature, negative indices are always out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3];
+	///
+	/// // SAFETY: the index is in bounds.
+	/// *unsafe { a.mut_at_g::<false, _>(2i32) } = 0;
+	/// assert_eq!(a, [1, 2, 0]);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	unsafe fn mut_at_g<const CHECKED: bool, T>(&mut self, idx: impl ToIndex) -> &mut T
+	where
+		Self: AsMut<[T]>,
+	{
+		if CHECKED {
+			return self.mut_at(idx);
+		}
+		let slice = self.as_mut();
+		match check_index(idx, slice.len()) {
+			// SAFETY: `check_index` only returns indices that are in bounds.
+			Some(i) => unsafe { slice.get_unchecked_mut(i) },
+			// SAFETY: the caller guarantees that the index is in bounds.
+			None => unsafe { core::hint::unreachable_unchecked() },
+		}
+	}
+
+	/// Iterate over a rolling polynomial hash of every window of `window` bytes, for substring search
+	/// with the Rabin-Karp algorithm. The `i`th hash is that of `bytes[i..i + window]`, so a match can
+	/// be sliced out with [`slice_at`](At::slice_at). Yields nothing if `window` is zero or longer than
+	/// the slice.
+	///
+	/// The hash of bytes `b_0, ..., b_{k-1}` is `b_0 * 257^(k-1) + ... + b_{k-1}` modulo the Mersenne prime
+	/// `2^61 - 1`. Each hash is computed from the previous one, so the whole iteration takes linear time.
+	/// Equal windows always have equal hashes, but different windows may collide, so matches should be
+	/// compared afterwards.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let text = b"abcabc";
+	///
+	/// let hashes: Vec<u64> = text.rolling_hashes(3).collect();
+	/// assert_eq!(hashes.len(), 4);
+	/// assert_eq!(hashes[0], hashes[3]);
+	/// assert_ne!(hashes[0], hashes[1]);
+	/// assert_eq!(text.rolling_hashes(7).count(), 0);
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rolling_hashes(&self, window: usize) -> impl Iterator<Item = u64>
+	where
+		Self: AsRef<[u8]>,
+	{
+		const BASE: u64 = 257;
+		const MODULUS: u64 = (1 << 61) - 1;
+		// Both operands are less than `MODULUS`, so the product fits in a `u128`.
+		#[allow(clippy::cast_possible_truncation)]
+		fn mul_mod(a: u64, b: u64) -> u64 {
+			(u128::from(a) * u128::from(b) % u128::from(MODULUS)) as u64
+		}
+
+		let bytes = self.as_ref();
+		let valid = window != 0 && window <= bytes.len();
+		let (first, rest, top) = if valid {
+			let first = bytes[..window]
+				.iter()
+				.fold(0, |hash, &b| (mul_mod(hash, BASE) + u64::from(b)) % MODULUS);
+			// The weight of the byte that leaves the window, `BASE^(window - 1)`.
+			let top = (1..window).fold(1, |power, _| mul_mod(power, BASE));
+			(Some(first), window..bytes.len(), top)
+		} else {
+			(None, 0..0, 0)
+		};
+
+		first
+			.into_iter()
+			.chain(rest.scan(first.unwrap_or(0), move |hash, i| {
+				let without =
+					(*hash + MODULUS - mul_mod(u64::from(bytes[i - window]), top)) % MODULUS;
+				*hash = (mul_mod(without, BASE) + u64::from(bytes[i])) % MODULUS;
+				Some(*hash)
+			}))
+	}
+
+	/// Split the slice into `n` disjoint mutable chunks, for example to process each one in a scoped thread.
+	/// Every chunk has `len / n` elements, except the last one, which also gets the remaining `len % n`
+	/// elements. If `n` is greater than the length, all but the last chunk are empty. Requires the `alloc` feature.
+	///
+	/// # Panics
+	/// Panics if `n` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4, 5, 6, 7];
+	///
+	/// std::thread::scope(|s| {
+	///     for chunk in a.split_for_threads(3) {
+	///         s.spawn(|| chunk.iter_mut().for_each(|x| *x *= 10));
+	///     }
+	/// });
+	/// assert_eq!(a, [10, 20, 30, 40, 50, 60, 70]);
+	///
+	/// let lens: Vec<usize> = a.split_for_threads(3).iter().map(|c| c.len()).collect();
+	/// assert_eq!(lens, [2, 2, 3]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn split_for_threads<T>(&mut self, n: usize) -> alloc::vec::Vec<&mut [T]>
+	where
+		Self: AsMut<[T]>,
+	{
+		assert!(n != 0, "can't split a slice into zero chunks");
+		let mut rest = self.as_mut();
+		let chunk_len = rest.len() / n;
+		let mut chunks = alloc::vec::Vec::with_capacity(n);
+		for _ in 1..n {
+			let (chunk, tail) = rest.split_at_mut(chunk_len);
+			chunks.push(chunk);
+			rest = tail;
+		}
+		chunks.push(rest);
+		chunks
+	}
+
+	/// Find the first element for which `f` returns `Some`, returning its index counting from the end
+	/// (so `-1` is the last element) along with the mapped value. The index can be passed back to
+	/// [`ref_at`](At::ref_at). The search stops at the first match. Returns `None` if there's no match.
+	/// Not available with the `no-negative` feature.
+	///
+	/// # Panics
+	/// Panics if the index of the match can't be expressed as a negative `isize`, which only happens
+	/// for slices of zero-sized types.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let words = ["a", "12", "b", "34"];
+	///
+	/// assert_eq!(words.find_map_at(|w| w.parse::<i32>().ok()), Some((-3, 12)));
+	/// assert_eq!(words.find_map_at(|w| w.strip_prefix('z')), None);
+	/// ```
+	#[cfg(not(feature = "no-negative"))]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn find_map_at<T, R>(&self, mut f: impl FnMut(&T) -> Option<R>) -> Option<(isize, R)>
+	where
+		Self: AsRef<[T]>,
+	{
+		let (i, value) = self
+			.as_ref()
+			.iter()
+			.enumerate()
+			.find_map(|(i, x)| Some((i, f(x)?)))?;
+		let idx = self
+			.to_from_end(i)
+			.expect("the index can't be expressed as an isize");
+		Some((idx, value))
+	}
+
+	/// Find the last element for which `f` returns `Some`, like [`find_map_at`](At::find_map_at), but
+	/// searching from the end. The search stops at the first match. Not available with the `no-negative` feature.
+	///
+	/// # Panics
+	/// Panics if the index of the match can't be expressed as a negative `isize`, which only happens
+	/// for slices of zero-sized types.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let words = ["a", "12", "b", "34"];
+	///
+	/// assert_eq!(words.rfind_map_at(|w| w.parse::<i32>().ok()), Some((-1, 34)));
+	/// ```
+	#[cfg(not(feature = "no-negative"))]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn rfind_map_at<T, R>(&self, mut f: impl FnMut(&T) -> Option<R>) -> Option<(isize, R)>
+	where
+		Self: AsRef<[T]>,
+	{
+		let (i, value) = self
+			.as_ref()
+			.iter()
+			.enumerate()
+			.rev()
+			.find_map(|(i, x)| Some((i, f(x)?)))?;
+		let idx = self
+			.to_from_end(i)
+			.expect("the index can't be expressed as an isize");
+		Some((idx, value))
+	}
+
+	/// Partition the slice in place, so that the elements for which `pred` returns `true` come first,
+	/// and return the boundary between the two groups as a bound for [`slice_at`](At::slice_at).
+	/// The boundary counts from the end, so it's the negated number of elements that don't satisfy
+	/// `pred`, except if every element does: then it's the length, since `-0` would be the start.
+	/// Not available with the `no-negative` feature.
+	///
+	/// This takes linear time, calling `pred` once per element. The partition isn't stable: the elements
+	/// that satisfy `pred` keep their relative order, but the other elements may be reordered.
+	///
+	/// # Panics
+	/// Panics if the boundary can't be expressed as an `isize`, which only happens for slices of
+	/// zero-sized types.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let mut a = [1, 2, 3, 4, 5, 6];
+	///
+	/// let boundary = a.partition_around_at(|x| x % 3 == 0);
+	/// assert_eq!(boundary, -4);
+	/// assert_eq!(a.slice_at(..boundary), &[3, 6]);
+	/// assert!(a.slice_at(boundary..).iter().all(|x| x % 3 != 0));
+	///
+	/// assert_eq!(a.partition_around_at(|_| true), 6);
+	/// ```
+	#[cfg(not(feature = "no-negative"))]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn partition_around_at<T>(&mut self, mut pred: impl FnMut(&T) -> bool) -> isize
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+		let mut boundary = 0;
+		for i in 0..len {
+			if pred(&slice[i]) {
+				slice.swap(boundary, i);
+				boundary += 1;
+			}
+		}
+
+		let idx = if boundary == len {
+			isize::try_from(len).ok()
+		} else {
+			slice.to_from_end(boundary)
+		};
+		idx.expect("the boundary can't be expressed as an isize")
+	}
+
+	/// Iterate over the maximum of every window of `window` elements, in order, so the `i`th item is the
+	/// maximum of `slice[i..i + window]`. If several elements are the maximum, the last one is returned.
+	/// This takes linear time in total, using a monotonic deque of at most `window` positions.
+	/// Yields nothing if `window` is longer than the slice. Requires the `alloc` feature.
+	///
+	/// # Panics
+	/// Panics if `window` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 3, 2, 5, 4, 1];
+	///
+	/// assert!(a.sliding_max(3).eq(&[3, 5, 5, 5]));
+	/// assert_eq!(a.sliding_max(7).count(), 0);
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn sliding_max<'a, T: Ord + 'a>(&'a self, window: usize) -> impl Iterator<Item = &'a T>
+	where
+		Self: AsRef<[T]>,
+	{
+		sliding::sliding_extremes(self.as_ref(), window, |new, old| new >= old)
+	}
+
+	/// Iterate over the minimum of every window of `window` elements, like [`sliding_max`](At::sliding_max).
+	/// If several elements are the minimum, the last one is returned. Requires the `alloc` feature.
+	///
+	/// # Panics
+	/// Panics if `window` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 3, 2, 5, 4, 1];
+	///
+	/// assert!(a.sliding_min(3).eq(&[1, 2, 2, 1]));
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn sliding_min<'a, T: Ord + 'a>(&'a self, window: usize) -> impl Iterator<Item = &'a T>
+	where
+		Self: AsRef<[T]>,
+	{
+		sliding::sliding_extremes(self.as_ref(), window, |new, old| new <= old)
+	}
+
+	/// Iterate over the element with the maximum key in every window of `window` elements,
+	/// like [`sliding_max`](At::sliding_max). The key function may be called several times per element.
+	/// Requires the `alloc` feature.
+	///
+	/// # Panics
+	/// Panics if `window` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let words = ["a", "ccc", "bb", "d"];
+	///
+	/// assert!(words.sliding_max_by_key(2, |w| w.len()).eq(&["ccc", "ccc", "bb"]));
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn sliding_max_by_key<'a, T: 'a, K: Ord>(
+		&'a self,
+		window: usize,
+		mut key: impl FnMut(&T) -> K + 'a,
+	) -> impl Iterator<Item = &'a T>
+	where
+		Self: AsRef<[T]>,
+	{
+		sliding::sliding_extremes(self.as_ref(), window, move |new, old| key(new) >= key(old))
+	}
+
+	/// Resolve every index against the length of the slice, like [`resolve`], yielding `None` for the
+	/// indices that are out of bounds. Requires the `alloc` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.resolve_all(&[-1i64, 0, 3]), [Some(2), Some(0), None]);
+	/// # }
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn resolve_all<T>(&self, indices: &[impl ToIndex]) -> alloc::vec::Vec<Option<usize>>
+	where
+		Self: AsRef<[T]>,
+	{
+		let len = self.as_ref().len();
+		indices.iter().map(|&idx| check_index(idx, len)).collect()
+	}
+
+	/// Resolve every index against the length of the slice, stopping at the first one that's
+	/// out of bounds. Requires the `alloc` feature.
+	///
+	/// # Errors
+	/// Returns an [`IndexError`] for the first index that is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, IndexError};
+	/// let a = [1, 2, 3];
+	///
+	/// assert_eq!(a.try_resolve_all(&[-1i64, 0]), Ok(vec![2, 0]));
+	/// assert_eq!(a.try_resolve_all(&[-1i64, 3, -4]), Err(IndexError { index: 3, len: 3 }));
+	/// # }
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_resolve_all<T>(
+		&self,
+		indices: &[impl ToIndex],
+	) -> Result<alloc::vec::Vec<usize>, IndexError>
+	where
+		Self: AsRef<[T]>,
+	{
+		indices
+			.iter()
+			.map(|&idx| self.resolve_result(idx))
+			.collect()
+	}
+
+	/// Resolve every index against the length of the slice into `out`, stopping at the first one that's
+	/// out of bounds. This is the non-allocating version of [`try_resolve_all`](At::try_resolve_all).
+	/// If an index is out of bounds, the positions before it have already been written.
+	///
+	/// # Errors
+	/// Returns an [`IndexError`] for the first index that is out of bounds.
+	///
+	/// # Panics
+	/// Panics if `out` and `indices` have different lengths.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let a = [1, 2, 3];
+	/// let mut out = [0; 2];
+	///
+	/// a.resolve_into(&[-1i64, -3], &mut out).unwrap();
+	/// assert_eq!(out, [2, 0]);
+	/// assert!(a.resolve_into(&[0, -4], &mut out).is_err());
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn resolve_into<T>(&self, indices: &[impl ToIndex], out: &mut [usize]) -> Result<(), IndexError>
+	where
+		Self: AsRef<[T]>,
+	{
+		assert_eq!(
+			indices.len(),
+			out.len(),
+			"the output must have one position per index"
+		);
+		for (i, &idx) in out.iter_mut().zip(indices) {
+			*i = self.resolve_result(idx)?;
+		}
+		Ok(())
+	}
+
+	/// Copy `src` into the slice, starting at a particular index. Like the bounds of a range, the start may
+	/// also be the length of the slice, where only an empty `src` fits. Panics if the start is out of bounds
+	/// or if `src` would run past the end of the slice.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let mut buf = [1, 2, 3, 4, 5, 6];
+	///
+	/// buf.overwrite_at(-4, &[0, 0, 0, 0]);
+	/// assert_eq!(buf, [1, 2, 0, 0, 0, 0]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn overwrite_at<T: Copy>(&mut self, start: impl ToIndex, src: &[T])
+	where
+		Self: AsMut<[T]>,
+	{
+		match self.try_overwrite_at(start, src) {
+			Ok(()) => {}
+			#[cfg(feature = "unsafe-unchecked")]
+			Err(_) => unsafe { unreachable_unchecked() },
+			#[cfg(not(feature = "unsafe-unchecked"))]
+			Err(_) => panic_write_check(start, src.len(), self.as_mut().len()),
+		}
+	}
+
+	/// Copy `src` into the slice, starting at a particular index, like [`overwrite_at`](At::overwrite_at).
+	/// The slice is left unchanged if `src` doesn't fit.
+	///
+	/// # Errors
+	/// Returns [`RangeError::StartOutOfRange`] if the start is out of bounds, or [`RangeError::EndOutOfRange`]
+	/// with the position just past the last element that would be written if `src` would run past the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::{At, RangeError};
+	/// let mut buf = [1, 2, 3];
+	///
+	/// assert_eq!(buf.try_overwrite_at(-2, &[0, 0, 0]), Err(RangeError::EndOutOfRange(4)));
+	/// assert_eq!(buf.try_overwrite_at(-4, &[0]), Err(RangeError::StartOutOfRange(-4)));
+	/// assert_eq!(buf, [1, 2, 3]);
+	///
+	/// assert_eq!(buf.try_overwrite_at(-2, &[0, 0]), Ok(()));
+	/// assert_eq!(buf, [1, 0, 0]);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn try_overwrite_at<T: Copy>(
+		&mut self,
+		start: impl ToIndex,
+		src: &[T],
+	) -> Result<(), RangeError>
+	where
+		Self: AsMut<[T]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+		let Some(start_pos) = check_bound(start, len) else {
+			return Err(RangeError::StartOutOfRange(error::saturate_index(start)));
+		};
+		match start_pos.checked_add(src.len()) {
+			Some(end) if end <= len => {
+				slice[start_pos..end].copy_from_slice(src);
+				Ok(())
+			}
+			end => Err(RangeError::EndOutOfRange(error::saturate_index(
+				end.unwrap_or(usize::MAX),
+			))),
+		}
+	}
+
+	/// Iterate over every `n`-th element by reference, starting from a particular index and stepping
+	/// forward until the end of the slice, for decimating a signal. Like the bounds of a range, the phase
+	/// may also be the length of the slice, which yields nothing.
+	///
+	/// # Panics
+	/// Panics if the phase is out of bounds or if `n` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::At;
+	/// let signal = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+	///
+	/// assert!(signal.sample_every(4, 1).eq(&[1, 5, 9]));
+	/// assert!(signal.sample_every(4, -4).eq(&[6]));
+	/// assert!(signal.sample_every(2, -3).eq(&[7, 9]));
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn sample_every<'a, T: 'a>(
+		&'a self,
+		n: usize,
+		phase: impl ToIndex,
+	) -> impl Iterator<Item = &'a T>
+	where
+		Self: AsRef<[T]>,
+	{
+		assert!(n != 0, "sample interval must be non-zero");
+		let slice = self.as_ref();
+		slice[bound_or_panic(phase, slice.len())..]
+			.iter()
+			.step_by(n)
+	}
+
+	/// Return the negative index of the last element of a prefix of `target_len` elements, that is, the
+	/// index that resolves to `target_len - 1`. Returns `None` if `target_len` is zero, since an empty
+	/// prefix has no last element, or if it's greater than the length of the slice. This is
+	/// [`to_from_end`](At::to_from_end) of `target_len - 1`. Not available with the `no-negative` feature.
+	///
+	/// # Examples
+	/// ```
+	/// use at::At;
+	/// let a = [1, 2, 3, 4, 5];
+	///
+	/// assert_eq!(a.index_for_len(5), Some(-1));
+	/// assert_eq!(a.index_for_len(2), Some(-4));
+	/// assert_eq!(a.canonical_index(-4), Some(1));
+	/// assert_eq!(a.index_for_len(0), None);
+	/// assert_eq!(a.index_for_len(6), None);
+	/// ```
+	#[cfg(not(feature = "no-negative"))]
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	fn index_for_len<T>(&self, target_len: usize) -> Option<isize>
+	where
+		Self: AsRef<[T]>,
+	{
+		self.to_from_end(target_len.checked_sub(1)?)
+	}
+}
+
+impl<T: ?Sized> At for T {}
+
+mod test {
+	#[cfg(test)]
+	use crate::At;
+
+	#[test]
+	fn test_positive() {
+		extern crate std;
+		use std::vec;
+
+		let mut v = vec![1, 2, 3];
+		assert_eq!(v.at(0u8), 1);
+		assert_eq!(v.ref_at(1i128), &2);
+		assert_eq!(v.mut_at(2isize), &mut 3);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_negative() {
+		let mut v = [4, 5, 6];
+		assert_eq!(v.at(-1i8), 6);
+		assert_eq!(v.ref_at(-2i128), &5);
+		assert_eq!(v.mut_at(-3isize), &mut 4);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 1 but the index is -2")]
+	fn test_panic() {
+		let s = ["hi"];
+		let _ = s.at(-2);
+	}
+
+	#[test]
+	fn test_frac() {
+		let v = [1, 2, 3, 4];
+		assert_eq!(v.at_frac(0.5), &3);
+		assert_eq!(v.at_frac(-1.0), &1);
+		assert_eq!(v.at_frac(-0.0), &1);
+		assert_eq!([()].at_frac(1.0), &());
+	}
+
+	#[test]
+	#[should_panic(expected = "fraction out of range: expected a value in -1.0..=1.0 but got NaN")]
+	fn test_frac_nan() {
+		let _ = [1].at_frac(f64::NAN);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	#[allow(clippy::reversed_empty_ranges)]
+	fn test_inclusive_range() {
+		let v = [1, 2, 3, 4];
+		assert_eq!(v.slice_at(2..=2), &[3]);
+		assert_eq!(v.slice_at(-1..=-1), &[4]);
+		assert_eq!(v.slice_at(..=-1), &v);
+		assert_eq!(v.slice_at(2..=1), &[]);
+		assert_eq!(v.slice_at(..-4), &[]);
+		assert_eq!(
+			[(); usize::MAX].slice_at(..=usize::MAX - 1).len(),
+			usize::MAX
+		);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "range out of bounds: the len is 4 but the range is 3..=1")]
+	#[allow(clippy::reversed_empty_ranges)]
+	fn test_reversed_inclusive_range() {
+		let _ = [1, 2, 3, 4].slice_at(3..=1);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "range out of bounds: the len is 4 but the range is ..=4")]
+	fn test_inclusive_range_end() {
+		let _ = [1, 2, 3, 4].slice_at(..=4);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "range out of bounds: the len is 4 but the range is ..<negative>")]
+	fn test_unrepresentable_range_end() {
+		let _ = [1, 2, 3, 4].slice_at(..i128::MIN);
+	}
+
+	#[test]
+	fn test_unrepresentable_range_debug() {
+		extern crate std;
+		use crate::SignedRange;
+
+		let range = SignedRange::from(..i128::MIN);
+		assert_eq!(std::format!("{range:?}"), "..<negative>");
+		let range = SignedRange::from(u128::MAX..);
+		assert_eq!(std::format!("{range:?}"), "<too large>..");
+		let range = SignedRange::from(1..=u128::MAX);
+		assert_eq!(std::format!("{range:?}"), "1..=<too large>");
+	}
+
+	#[test]
+	#[cfg(all(feature = "alloc", not(feature = "structured-panic")))]
+	#[should_panic(expected = "index out of bounds: the len is 2 but the index is 3")]
+	fn test_at_or_push_panic() {
+		extern crate std;
+		use crate::VecAt;
+		use std::vec;
+
+		let _ = vec![1, 2].at_or_push(3, || 0);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_zst() {
+		let giant = [(); usize::MAX];
+		giant.at(-1);
+		giant.at(usize::MAX - 1);
+		giant.at(isize::MIN);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_copy_primitives() {
+		let chars: &[char] = &['x', 'y', 'z'];
+		assert_eq!(chars.at(-1), 'z');
+		assert_eq!(chars.at(0u8), 'x');
+
+		let bools: &[bool] = &[true, false];
+		assert!(!bools.at(-1));
+		assert!(bools.at(-2i64));
+
+		let floats: &[f32] = &[0.5, 1.5, 2.5];
+		assert_eq!(floats.at(-2).to_bits(), 1.5f32.to_bits());
+		assert_eq!([0.25f32; 4].at(3usize).to_bits(), 0.25f32.to_bits());
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_neighbor_wrapping() {
+		let v = [0, 1, 2];
+		assert_eq!(v.neighbor_at(2, 1), &0);
+		assert_eq!(v.neighbor_at(0, 3), &0);
+		assert_eq!(v.neighbor_at(-1, isize::MAX), &0);
+		assert_eq!(v.neighbor_at(0, isize::MIN), &1);
+
+		let giant = [(); usize::MAX];
+		giant.neighbor_at(-1, isize::MAX);
+		giant.neighbor_at(0, isize::MIN);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_bytes() {
+		let bytes = bytes::Bytes::from_static(b"abc");
+		assert_eq!(bytes.at(-1), b'c');
+		assert_eq!(bytes.ref_at(0), &b'a');
+
+		let mut bytes_mut = bytes::BytesMut::from(&b"xyz"[..]);
+		*bytes_mut.mut_at(-1) = b'!';
+		assert_eq!(bytes_mut.at(-1), b'!');
+		assert_eq!(bytes_mut.slice_at(..-1), b"xy");
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_inline_vecs() {
+		let mut av = arrayvec::ArrayVec::<_, 4>::from_iter([1, 2, 3]);
+		assert_eq!(av.ref_at(-1), &3);
+		*av.mut_at(-1) = 4;
+		assert_eq!(av.at(-1), 4);
+
+		let mut sv = smallvec::SmallVec::<[_; 2]>::from_slice(&[1, 2, 3]);
+		*sv.mut_at(-1) = 4;
+		assert_eq!(sv.ref_at(-1), &4);
+		assert_eq!(sv.slice_at(..-1), &[1, 2]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_index_error_saturation() {
+		use crate::IndexError;
+
+		let err = IndexError::from([1].try_typed_at(-5i8).unwrap_err());
+		assert_eq!(err, IndexError { index: -5, len: 1 });
+
+		let err = IndexError::from([1].try_typed_at(u128::MAX).unwrap_err());
+		assert_eq!(err.index, isize::MAX);
+
+		let err = IndexError::from([1].try_typed_at(i128::MIN).unwrap_err());
+		assert_eq!(err.index, isize::MIN);
+	}
+
+	#[test]
+	#[cfg(all(feature = "structured-panic", not(feature = "no-negative")))]
+	fn test_structured_panic() {
+		extern crate std;
+		use crate::IndexError;
+
+		let payload = std::panic::catch_unwind(|| [1, 2].at(-3)).unwrap_err();
+		assert_eq!(
+			payload.downcast_ref(),
+			Some(&IndexError { index: -3, len: 2 })
+		);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_borrowed_slice_lifetime() {
+		// The result must borrow from the slice itself, not from the local reference to it.
+		fn last(s: &[u8]) -> &u8 {
+			s.ref_at(-1)
+		}
+
+		fn tail(s: &str) -> &[u8] {
+			s.slice_at(1..)
+		}
+
+		assert_eq!(last(&[1, 2]), &2);
+		assert_eq!(tail("abc"), b"bc");
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_gather_mut_order() {
+		let mut v = [0, 1, 2, 3, 4];
+		let [a, b, c] = v.gather_mut([3, -1, 0i8]).unwrap();
+		assert_eq!((*a, *b, *c), (3, 4, 0));
+		*a += 10;
+		*b += 20;
+		*c += 30;
+		assert_eq!(v, [30, 1, 2, 13, 24]);
+
+		assert_eq!(v.gather_mut::<i32, 0>([0u8; 0]), Some([]));
+		assert!(v.gather_mut([-5, 0]).is_none());
+		assert!(v.gather_mut([u128::MAX]).is_none());
+		assert!(v.gather_mut([4, 2, 4]).is_none());
+		assert!(v.gather_mut([4, 3, 2, 1, 0]).is_some());
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_border_modes() {
+		use crate::BorderMode;
+
+		let v = [0, 1, 2, 3];
+		// Positions after the end, then positions before the start.
+		assert_eq!([4, 5, 6, 7].map(|i| *v.mirror_at(i)), [2, 1, 0, 1]);
+		assert_eq!([-5, -6, -7, -8].map(|i| *v.mirror_at(i)), [1, 2, 3, 2]);
+
+		assert_eq!(v.wrapping_at(usize::MAX), &3);
+		assert_eq!(v.wrapping_at(isize::MIN), &0);
+		assert_eq!(v.saturating_at(isize::MIN), &0);
+		assert_eq!(v.mirror_at(usize::MAX), &3);
+		assert_eq!([5].mirror_at(-100), &5);
+
+		for mode in [BorderMode::Wrap, BorderMode::Clamp, BorderMode::Mirror] {
+			assert_eq!(v.at_with(-1, mode), &3);
+			assert_eq!(v.at_with(2u8, mode), &2);
+		}
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 0 but the index is 3")]
+	fn test_border_mode_empty() {
+		let _ = [0u8; 0].wrapping_at(3);
+	}
+
+	#[test]
+	#[should_panic(
+		expected = "read out of bounds: the len is 3 but the read is 4 bytes at index -3"
+	)]
+	fn test_read_int_too_short() {
+		let _ = [1u8, 2, 3].read_u32_be_at(-3);
+	}
+
+	#[test]
+	#[cfg(feature = "no-negative")]
+	fn test_no_negative() {
+		use crate::{IndexOutcome, OutOfBounds};
+
+		let mut v = [1, 2, 3];
+		assert_eq!(v.classify_at(-1), IndexOutcome::Unrepresentable);
+		assert_eq!(v.try_typed_at(-1), Err(OutOfBounds { index: -1, len: 3 }));
+		assert_eq!(v.slice_at(1..), &[2, 3]);
+		assert!(v.gather_mut([-1]).is_none());
+		assert_eq!(
+			v.slice_at_ctx(..-3),
+			Err(crate::RangeError::EndOutOfRange(isize::MIN))
+		);
+	}
+
+	#[test]
+	#[cfg(all(feature = "no-negative", not(feature = "structured-panic")))]
+	#[should_panic(expected = "range out of bounds: the len is 2 but the range is <negative>..")]
+	fn test_no_negative_range_panic() {
+		let _ = [1, 2].slice_at(-1..);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_concrete_indices() {
+		let v = [1, 2, 3];
+
+		for i in -3..3 {
+			assert_eq!(v.iat(i), v.ref_at(i));
+		}
+		for i in 0..3 {
+			assert_eq!(v.uat(i), v.ref_at(i));
+		}
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is -4")]
+	fn test_concrete_index_panic() {
+		let _ = [1, 2, 3].iat(-4);
+	}
+
+	#[test]
+	#[cfg(all(feature = "alloc", not(feature = "no-negative")))]
+	fn test_spare_capacity() {
+		use alloc::vec::Vec;
+
+		let mut v = Vec::with_capacity(4);
+		v.push(1);
+		let spare = v.spare_capacity_mut();
+		let last = spare.len() - 1;
+		spare.mut_at(-1).write(4);
+
+		// SAFETY: the last spare element was just initialized.
+		assert_eq!(unsafe { spare[last].assume_init() }, 4);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is -4")]
+	fn test_sign_magnitude_panic() {
+		let _ = [1, 2, 3].signed_at(true, 4);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_resolve_invariants() {
+		for len in 0..8 {
+			for i in 0..16 {
+				assert_eq!(crate::resolve(i, len), (i < len).then_some(i));
+			}
+			for k in 1..16isize {
+				let expected = len.checked_sub(k.unsigned_abs());
+				assert_eq!(crate::resolve(-k, len), expected);
+			}
+			assert_eq!(crate::resolve(i128::MAX, len), None);
+		}
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is -4")]
+	fn test_triplet_panic() {
+		let _ = [1, 2, 3].triplet_at(0, crate::BorderMode::Panic);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_bits_across_bytes() {
+		let data = [0xff; 9];
+		assert_eq!(data.bits_at(3, 64), u64::MAX);
+		assert_eq!(data.bits_at(-64, 64), u64::MAX);
+		assert_eq!([0x12, 0x34, 0x56].bits_at(4, 16), 0x6341);
+	}
+
+	#[test]
+	#[cfg(all(debug_assertions, not(feature = "no-negative")))]
+	#[should_panic(expected = "invariant does not hold for the element at index -1")]
+	fn test_invariant_violated() {
+		let _ = [1, 2, 3].ref_at_checked_with(-1, |&x| x == 0);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_try_at_short_circuits() {
+		fn first_and_last(v: &[u8]) -> Option<(u8, &u8)> {
+			Some((v.try_at(0)?, v.try_ref_at(-1)?))
+		}
+
+		assert_eq!(first_and_last(&[1, 2]), Some((1, &2)));
+		assert_eq!(first_and_last(&[]), None);
+	}
+
+	#[test]
+	#[cfg(all(debug_assertions, not(feature = "no-negative")))]
+	#[should_panic(expected = "an element accessed with `ref_at_must_use` was never read")]
+	fn test_must_use_unread() {
+		let _ = [1, 2, 3].ref_at_must_use(-1);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_ring_wraparound() {
+		let mut ring = crate::Ring::from_parts([0, 1, 2, 3], 2, 3);
+		assert_eq!([ring.at(0), ring.at(1), ring.at(2)], [2, 3, 0]);
+
+		assert_eq!(ring.push(4), None);
+		assert_eq!(ring.push(5), Some(2));
+		*ring.mut_at(-1) += 10;
+		assert_eq!(
+			[ring.at(-4), ring.at(-3), ring.at(-2), ring.at(-1)],
+			[3, 0, 4, 15]
+		);
+		assert_eq!(crate::Ring::<i32, 0>::default().push(1), Some(1));
+	}
+
+	#[test]
+	#[cfg(all(not(feature = "structured-panic"), not(feature = "no-negative")))]
+	#[should_panic(expected = "column index out of bounds: the len is 3 but the index is 3")]
+	fn test_grid_column_panic() {
+		let _ = [[1, 2, 3], [4, 5, 6]].at2(-1, 3);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 6 but the index is -7")]
+	fn test_tiled_panic() {
+		let _ = [1, 2, 3].tiled_at(2, -7);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is End - 4")]
+	fn test_end_panic() {
+		let _ = [1, 2, 3].ref_at(crate::End - 2 - 2);
+	}
+
+	#[test]
+	#[cfg(all(feature = "instrument", feature = "std"))]
+	fn test_oob_count() {
+		let before = crate::oob_count();
+		let result = std::panic::catch_unwind(|| [1, 2, 3].at(3));
+		assert!(result.is_err());
+		// Other tests may panic concurrently, so the count can increase by more than one.
+		assert!(crate::oob_count() > before);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(
+		expected = "offset out of bounds: the len is 4 but the offset is -3 from the anchor 2"
+	)]
+	fn test_anchored_panic() {
+		let _ = crate::AnchoredSlice::new(&[1, 2, 3, 4], 2).at(-3);
+	}
+
+	#[test]
+	#[should_panic(expected = "position out of range: the len is 2 but the position is -2.5")]
+	fn test_lerp_out_of_range() {
+		let _ = [1.0, 2.0].lerp_at(-2.5);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_clamped_at() {
+		let a = [1, 2, 3];
+		assert_eq!(a.clamped_at(0), (false, &1));
+		assert_eq!(a.clamped_at(-3), (false, &1));
+		assert_eq!(a.clamped_at(-4), (true, &1));
+		assert_eq!(a.clamped_at(3u8), (true, &3));
+		assert_eq!(a.try_clamped_at(i128::MAX), None);
+		assert_eq!([0u8; 0].try_clamped_at(-1), None);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_strided_at() {
+		let m = [1, 2, 3, 4, 5, 6, 7];
+		assert_eq!(m.strided_at(3, 0), &1);
+		assert_eq!(m.strided_at(3, -1), &7);
+		let column = crate::StridedView::new(&m, 2, 3);
+		assert_eq!(column.len(), 2);
+		assert_eq!(column.at(-1), 6);
+		assert_eq!(column.try_ref_at(2), None);
+		assert!(crate::StridedView::new(&m, 7, 3).is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "the stride must not be zero")]
+	fn test_strided_at_zero_stride() {
+		let _ = [1, 2, 3].strided_at(0, 0);
+	}
+
+	#[test]
+	fn test_rolling_mut_at() {
+		// Exponential smoothing with a factor of 1/2.
+		let mut a = [8u32, 0, 0, 4];
+		a.rolling_mut_at(|prev, cur| *cur = u32::midpoint(*prev, *cur));
+		assert_eq!(a, [8, 4, 2, 3]);
+
+		let mut a = [8u32, 0, 0, 4];
+		a.rev_rolling_mut_at(|next, cur| *cur = u32::midpoint(*next, *cur));
+		assert_eq!(a, [4, 1, 2, 4]);
+
+		let mut single = [1];
+		single.rolling_mut_at(|_: &i32, _| unreachable!());
+		single.rev_rolling_mut_at(|_: &i32, _| unreachable!());
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_forward_and_from_end() {
+		use crate::{End, Forward, FromEnd};
+
+		let a = [1, 2, 3];
+		assert_eq!(a.at(Forward(2)), 3);
+		assert_eq!(a.at(FromEnd(3)), 1);
+		assert_eq!(FromEnd(2), End - 2);
+		assert_eq!(a.try_ref_at(Forward(usize::MAX)), None);
+		assert_eq!(a.try_ref_at(FromEnd(4)), None);
+		assert_eq!(a.slice_at(FromEnd(3)..FromEnd(1)), &[1, 2]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_eq_at() {
+		let a = [1, 2, 3, 1, 2];
+		assert!(a.eq_at(0, &a, -2, 2));
+		assert!(a.eq_at(-1, &[2], 0, 1));
+		assert!(a.eq_at(5, &[], 0, 0));
+		assert!(!a.eq_at(0, &a, -2, 3));
+		assert!(!a.eq_at(6, &a, 0, 0));
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_torus_grid() {
+		let mut cells = [0u8; 12];
+		let mut grid = crate::TorusGrid::new(&mut cells[..], 4);
+		*grid.mut_at2(-1, -1) = 1;
+		*grid.mut_at2(3, 4) = 2;
+		assert_eq!(grid.copy_at2(2, 3), 1);
+		assert_eq!(grid.at2(-3, -4), &2);
+		assert_eq!(grid.width(), 4);
+		assert_eq!(cells, [2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+	}
+
+	#[test]
+	#[should_panic(expected = "the len is 5 but the width is 2")]
+	fn test_torus_grid_ragged() {
+		let _ = crate::TorusGrid::new([0; 5], 2);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_window_around_at() {
+		let a = [1, 2, 3, 4, 5];
+		assert_eq!(a.window_around_at(0, 0), &[1]);
+		assert_eq!(a.window_around_at(-3, 2), &a);
+		assert_eq!(a.window_around_at(1, usize::MAX), &a);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+	fn test_window_around_at_empty() {
+		let _ = [0u8; 0].window_around_at(0, 1);
+	}
+
+	#[test]
+	#[cfg(feature = "zerocopy")]
+	#[should_panic(
+		expected = "read out of bounds: the len is 3 but the read is 4 bytes at index -2"
+	)]
+	fn test_read_at_short() {
+		let _: u32 = [1u8, 2, 3].read_at(-2);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_at_ctx() {
+		use crate::OobContext;
+
+		let a = [1, 2, 3];
+		assert_eq!(a.at_ctx(0), Ok(&1));
+		assert_eq!(
+			a.at_ctx(-4),
+			Err(OobContext {
+				requested: -4,
+				len: 3,
+				nearest_valid: Some(0)
+			})
+		);
+		assert_eq!(a.at_ctx(u128::MAX).unwrap_err().nearest_valid, Some(-1));
+		assert_eq!([0u8; 0].at_ctx(0).unwrap_err().nearest_valid, None);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_boxed_trait_objects() {
+		extern crate std;
+		use std::boxed::Box;
+		use std::fmt::Debug;
+		use std::vec;
+
+		let mut v: std::vec::Vec<Box<dyn Debug>> = vec![Box::new(1), Box::new("two")];
+		assert_eq!(std::format!("{:?}", v.ref_at(-1)), "\"two\"");
+		*v.mut_at(0) = Box::new(3.5);
+		assert_eq!(std::format!("{:?}", v.try_ref_at(-2)), "Some(3.5)");
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_stack() {
+		let values = [1, 2, 3, 4];
+		let stack = crate::Stack::new(&values, 2);
+		assert_eq!(stack.at(0), 2);
+		assert_eq!(stack.at(-2), 2);
+		assert_eq!(stack.as_slice(), &[1, 2]);
+		assert_eq!(stack.try_ref_at(2), None);
+		assert_eq!(crate::Stack::new(&values, 0).try_ref_at(0), None);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+	fn test_stack_too_deep() {
+		let _ = crate::Stack::new(&[1, 2, 3], 2).at(2);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_slice_bounds_at() {
+		use core::ops::Bound::{Excluded, Included, Unbounded};
+
+		let a = [1, 2, 3, 4];
+		assert_eq!(a.slice_bounds_at(Unbounded, Unbounded), &a);
+		assert_eq!(a.slice_bounds_at(Excluded(-1), Unbounded), &[]);
+		assert_eq!(a.slice_bounds_at(Included(1), Excluded(-1)), &[2, 3]);
+		assert_eq!(a.slice_at((Excluded(1u8), Included(2u8))), &[3]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(
+		expected = "range out of bounds: the len is 3 but the range is (Excluded(2), Excluded(1))"
+	)]
+	fn test_slice_bounds_at_reversed() {
+		use core::ops::Bound::Excluded;
+
+		let _ = [1, 2, 3].slice_bounds_at(Excluded(2), Excluded(1));
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_resolved_range() {
+		use crate::ResolvedRange;
+
+		let a = [1, 2, 3, 4];
+		let r = ResolvedRange::new(..-1, a.len()).unwrap();
+		assert_eq!(r.get(&a), &[1, 2, 3]);
+		assert_eq!(r.slice_len(), 4);
+		assert_eq!(ResolvedRange::new(-5.., a.len()), None);
+	}
+
+	#[test]
+	#[cfg(all(debug_assertions, not(feature = "no-negative")))]
+	#[should_panic(expected = "the slice length has changed")]
+	fn test_resolved_range_stale() {
+		let r = crate::ResolvedRange::new(-2.., 4).unwrap();
+		let _ = r.get(&[1, 2, 3]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_generic_array() {
+		use generic_array::{GenericArray, typenum::U4};
+
+		let mut block: GenericArray<u8, U4> = GenericArray::from_array([1, 2, 3, 4]);
+		assert_eq!(block.ref_at(-1), &4);
+		*block.mut_at(0) = 5;
+		assert_eq!(block.at(0), 5);
+		assert_eq!(block.slice_at(-2..), &[3, 4]);
+	}
+
+	#[test]
+	fn test_trim_matches_at() {
+		let a = [1, 2, 3, 2, 1];
+		assert_eq!(a.trim_start_matches_at(|&x| x < 3), &[3, 2, 1]);
+		assert_eq!(a.trim_end_matches_at(|&x| x < 3), &[1, 2, 3]);
+		assert_eq!(a.trim_matches_at(|&x| x < 3), &[3]);
+		assert_eq!(a.trim_matches_at(|&x| x > 0), &[]);
+		assert_eq!(a.trim_matches_at(|_| false), &a);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_iter_mut_at() {
+		let mut a = [1, 2, 3, 4];
+		for (i, x) in a.iter_mut_at(1..).enumerate() {
+			*x += i;
+		}
+		assert_eq!(a, [1, 2, 4, 6]);
+		assert_eq!(a.iter_mut_at(-1..-1).count(), 0);
+	}
+
+	#[test]
+	#[cfg(all(not(feature = "structured-panic"), not(feature = "no-negative")))]
+	#[should_panic(expected = "range out of bounds: the len is 2 but the range is -3..")]
+	fn test_iter_mut_at_out_of_bounds() {
+		let _ = [1, 2].iter_mut_at(-3..);
+	}
+
+	#[test]
+	fn test_cycle_pairs_at() {
+		let mut pairs = [1, 2].cycle_pairs_at();
+		assert_eq!(pairs.next(), Some((&1, &2)));
+		assert_eq!(pairs.next(), Some((&2, &1)));
+		assert_eq!(pairs.next(), None);
+
+		let mut single = [1].cycle_pairs_at();
+		assert_eq!(single.next(), Some((&1, &1)));
+		assert_eq!(single.next(), None);
+		assert_eq!([0u8; 0].cycle_pairs_at().next(), None);
+	}
+
+	// Extreme indices for every accessor, to make sure the overflow reasoning in
+	// `resolve_unbounded` holds on every path and not just the one `test_zst` covers.
+	// Like the panic tests, these are skipped with features that change what out-of-bounds accesses do.
+	#[cfg(all(
+		test,
+		not(any(feature = "structured-panic", feature = "unsafe-unchecked"))
+	))]
+	mod extremes {
+		extern crate std;
+		use crate::At;
+		use std::panic::catch_unwind;
+
+		const A: [i32; 3] = [1, 2, 3];
+
+		// Indices that are out of bounds for any slice of non-zero-sized types.
+		macro_rules! for_each_extreme {
+			($idx:ident => $body:expr) => {{
+				{
+					let $idx = isize::MIN;
+					$body;
+				}
+				{
+					let $idx = isize::MAX;
+					$body;
+				}
+				{
+					let $idx = usize::MAX;
+					$body;
+				}
+				{
+					let $idx = i128::MIN;
+					$body;
+				}
+				{
+					let $idx = u128::MAX;
+					$body;
+				}
+			}};
+		}
+
+		#[test]
+		fn test_try_accessors() {
+			let mut a = A;
+			for_each_extreme!(idx => {
+				assert_eq!(a.try_at(idx), None, "{idx}");
+				assert_eq!(a.try_ref_at(idx), None, "{idx}");
+				assert_eq!(a.try_mut_at(idx), None, "{idx}");
+				assert!(a.validate(idx).is_none(), "{idx}");
+			});
+		}
+
+		#[test]
+		fn test_panicking_accessors() {
+			for_each_extreme!(idx => {
+				assert!(catch_unwind(|| A.at(idx)).is_err(), "{idx}");
+				assert!(catch_unwind(|| *A.ref_at(idx)).is_err(), "{idx}");
+				assert!(catch_unwind(|| *A.clone().mut_at(idx)).is_err(), "{idx}");
+			});
+		}
+
+		#[test]
+		fn test_border_accessors() {
+			// The wrapped position is `usize::MAX` modulo 3.
+			assert_eq!(A.wrapping_at(usize::MAX), &1);
+			assert_eq!(A.saturating_at(usize::MAX), &3);
+			assert_eq!(A.saturating_at(isize::MAX), &3);
+		}
+
+		#[test]
+		#[cfg(not(feature = "no-negative"))]
+		fn test_signed_border_accessors() {
+			// The wrapped position is `len + isize::MIN` modulo 3.
+			assert_eq!(A.wrapping_at(isize::MIN), &2);
+			assert_eq!(A.saturating_at(isize::MIN), &1);
+		}
+
+		#[test]
+		fn test_narrow_signed() {
+			let a: [u8; 129] = core::array::from_fn(|i| u8::try_from(i).unwrap());
+			for len in [1, 127, 128, 129] {
+				let s = &a[..len];
+				// Without negative indices, every negative value is out of bounds.
+				let signed = !cfg!(feature = "no-negative");
+				let expected_min = (signed && len >= 128).then(|| &a[len - 128]);
+				assert_eq!(s.try_ref_at(i8::MIN), expected_min, "{len}");
+				assert_eq!(s.try_ref_at(i8::MAX), (len >= 128).then_some(&127), "{len}");
+				assert_eq!(s.try_ref_at(i16::MIN), None, "{len}");
+				if expected_min.is_none() {
+					assert!(catch_unwind(|| s.at(i8::MIN)).is_err(), "{len}");
+				}
+				if len < 128 {
+					assert!(catch_unwind(|| s.at(i8::MAX)).is_err(), "{len}");
+				}
+				assert!(catch_unwind(|| s.at(i16::MIN)).is_err(), "{len}");
+			}
+		}
+
+		#[test]
+		fn test_zst_accessors() {
+			let giant = [(); usize::MAX];
+			assert_eq!(giant.try_ref_at(usize::MAX - 1), Some(&()));
+			assert_eq!(giant.try_ref_at(usize::MAX), None);
+			assert_eq!(giant.try_ref_at(i128::MIN), None);
+			assert_eq!(giant.wrapping_at(usize::MAX), &());
+			assert_eq!(giant.saturating_at(usize::MAX), &());
+		}
+
+		#[test]
+		#[cfg(not(feature = "no-negative"))]
+		fn test_signed_zst_accessors() {
+			let mut giant = [(); usize::MAX];
+			assert_eq!(giant.try_ref_at(isize::MIN), Some(&()));
+			assert_eq!(giant.try_mut_at(isize::MIN), Some(&mut ()));
+			assert_eq!(giant.try_ref_at(-isize::MAX), Some(&()));
+		}
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_at_flagged() {
+		use crate::EndFlags;
+
+		let both = EndFlags {
+			is_first: true,
+			is_last: true,
+		};
+		assert_eq!([1].at_flagged(-1), (&1, both));
+		assert_eq!([1, 2, 3].at_flagged(1), (&2, EndFlags::default()));
+		assert!([1, 2, 3].at_flagged(-1).1.is_last);
+	}
+
+	#[test]
+	fn test_at_saturating_convert() {
+		let a = [1, 2, 3];
+		assert_eq!(a.at_saturating_convert(i128::MAX), &3);
+		assert_eq!(a.at_saturating_convert(i128::MIN + 1), &1);
+		assert_eq!(a.at_saturating_convert(usize::MAX), &3);
+		assert_eq!(a.at_saturating_convert(1u8), &2);
+
+		let giant = [(); usize::MAX];
+		assert_eq!(giant.at_saturating_convert(u128::MAX), &());
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(
+		expected = "index out of bounds: the len is 0 but the index is 340282366920938463463374607431768211455"
+	)]
+	fn test_at_saturating_convert_empty() {
+		let _ = [0u8; 0].at_saturating_convert(u128::MAX);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_rev_view() {
+		let a = [1, 2, 3];
+		let rev = a.rev_view();
+		assert_eq!(rev.at(2), 1);
+		assert_eq!(rev.at(-3), 3);
+		assert_eq!(rev.try_ref_at(-4), None);
+		assert_eq!(rev.len(), 3);
+		assert!([0u8; 0].rev_view().is_empty());
+		assert_eq!([0u8; 0].rev_view().try_ref_at(0), None);
+	}
+
+	#[test]
+	fn test_runs_at() {
+		assert_eq!([0u8; 0].runs_at().next(), None);
+		assert_eq!([7].runs_at().last(), Some(&[7][..]));
+		let runs = [1, 1, 2, 1].runs_at();
+		assert_eq!(runs.map(<[_]>::len).max(), Some(2));
+		let a = ["ab", "ac", "b"];
+		assert_eq!(a.runs_by_key_at(|s| s.as_bytes()[0]).count(), 2);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_neighborhood_mut_at() {
+		let mut a = [1, 2, 3, 4];
+		a.neighborhood_mut_at(0, 1).fill(0);
+		assert_eq!(a, [0, 0, 3, 4]);
+		a.neighborhood_mut_at(-1, usize::MAX).reverse();
+		assert_eq!(a, [4, 3, 0, 0]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_stencil_at() {
+		let a = [1, 2, 3];
+		assert_eq!(a.stencil_at(0, []), [&0; 0]);
+		assert_eq!(a.stencil_at(-1, [0, -2, -1]), [&3, &1, &2]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is -4")]
+	fn test_stencil_at_out_of_bounds() {
+		let _ = [1, 2, 3].stencil_at(0, [-1, 0, 1]);
+	}
+
+	#[test]
+	#[cfg(all(feature = "alloc", not(feature = "no-negative")))]
+	fn test_mut_at_grow() {
+		extern crate std;
+		use crate::VecAt;
+		use std::vec;
+
+		let mut v: std::vec::Vec<u8> = vec![];
+		*v.mut_at_grow(0u8) = 1;
+		*v.mut_at_grow(2) = 3;
+		*v.mut_at_grow(-2) = 2;
+		assert_eq!(v, [1, 2, 3]);
+	}
+
+	#[test]
+	#[cfg(all(feature = "alloc", not(feature = "structured-panic")))]
+	#[should_panic(expected = "index out of bounds: the len is 1 but the index is -2")]
+	fn test_mut_at_grow_negative() {
+		extern crate std;
+		use crate::VecAt;
+		use std::vec;
+
+		let _ = vec![1].mut_at_grow(-2);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_rotate_to_front_at() {
+		let mut a = [1, 2, 3];
+		a.rotate_to_front_at(0);
+		assert_eq!(a, [1, 2, 3]);
+		a.rotate_to_front_at(-2);
+		assert_eq!(a, [2, 3, 1]);
+		[0u8; 0].rotate_to_front_at(5);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+	fn test_rotate_to_front_at_out_of_bounds() {
+		[1, 2, 3].rotate_to_front_at(3);
+	}
+
+	#[test]
+	fn test_dot_at() {
+		let a = [1.0, 2.0, 3.0];
+		assert!((a.dot_at(&[0.5, 0.5, 0.5, 9.0], ..) - 3.0f64).abs() < f64::EPSILON);
+		assert_eq!([1u8, 2].dot_at(&[3], 1..), 6);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	#[should_panic(expected = "the range has 2 elements but the other slice only has 1")]
+	fn test_dot_at_short() {
+		let _ = [1, 2, 3].dot_at(&[1], -2..);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_hold_at() {
+		let a = [1, 2, 3];
+		assert_eq!(a.hold_at(0), &1);
+		assert_eq!(a.hold_at(usize::MAX), &3);
+		assert_eq!(a.hold_at(u128::MAX), &3);
+		assert_eq!(a.hold_at(-1), &3);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is -4")]
+	fn test_hold_at_negative() {
+		let _ = [1, 2, 3].hold_at(-4);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 0 but the index is 5")]
+	fn test_hold_at_empty() {
+		let _ = [0u8; 0].hold_at(5);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_binary_search_at() {
+		let a = [1, 2, 2, 4];
+		let found = a.binary_search_at(&4).unwrap();
+		assert_eq!(a.ref_at(found), &4);
+		assert_eq!([0u8; 0].binary_search_at(&1), Err(0));
+		assert_eq!([4, 2, 2, 1].binary_search_by_key_at(&-3, |&x| -x), Err(1));
+
+		let giant = [(); usize::MAX];
+		let found = giant.binary_search_at(&()).unwrap();
+		assert_eq!(giant.ref_at(found), &());
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_swap_ends_and_reverse_at() {
+		let mut a = [1, 2];
+		a.swap_ends();
+		assert_eq!(a, [2, 1]);
+		let mut single = [1];
+		single.swap_ends();
+		[0u8; 0].swap_ends();
+
+		let mut b = [1, 2, 3, 4];
+		b.reverse_at(-2..);
+		assert_eq!(b, [1, 2, 4, 3]);
+		b.reverse_at(..);
+		assert_eq!(b, [3, 4, 2, 1]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_halves_at() {
+		let mut a = [1, 2, 3];
+		assert_eq!(a.halves_at(0), (&[][..], &[1, 2, 3][..]));
+		assert_eq!(a.halves_at(-3), (&[][..], &[1, 2, 3][..]));
+		assert_eq!(a.halves_at(3u8), (&[1, 2, 3][..], &[][..]));
+		let (left, right) = a.halves_mut_at(3);
+		assert_eq!((left.len(), right.len()), (3, 0));
+		assert_eq!([0u8; 0].halves_at(0), (&[][..], &[][..]));
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is 4")]
+	fn test_halves_mut_at_out_of_bounds() {
+		let _ = [1, 2, 3].halves_mut_at(4);
+	}
+
+	#[test]
+	fn test_repeat_into() {
+		let mut buf = [0; 4];
+		crate::repeat_into(&[9], &mut buf);
+		assert_eq!(buf, [9; 4]);
+		crate::repeat_into(&[1, 2, 3, 4, 5], &mut buf);
+		assert_eq!(buf, [1, 2, 3, 4]);
+		crate::repeat_into::<u8>(&[], &mut []);
+	}
+
+	#[test]
+	#[should_panic(expected = "can't repeat an empty slice")]
+	fn test_repeat_into_empty() {
+		crate::repeat_into(&[], &mut [1]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_slice_at_ctx() {
+		extern crate std;
+		use crate::RangeError;
+		use std::string::ToString;
+
+		let a = [1, 2, 3];
+		assert_eq!(a.slice_at_ctx(..), Ok(&a[..]));
+		assert_eq!(a.slice_at_ctx(-4..), Err(RangeError::StartOutOfRange(-4)));
+		assert_eq!(a.slice_at_ctx(..=3), Err(RangeError::EndOutOfRange(3)));
+		assert_eq!(
+			a.slice_at_ctx(u128::MAX..),
+			Err(RangeError::StartOutOfRange(isize::MAX))
+		);
+		assert_eq!(
+			a.slice_at_ctx(-1..1).unwrap_err().to_string(),
+			"range starts at 2 but ends at 1"
+		);
+		assert_eq!(a.try_slice_at(-1..1), None);
+	}
+
+	#[test]
+	fn test_slice_at_ctx_saturated() {
+		use crate::RangeError;
+
+		let a = [1, 2, 3];
+		assert_eq!(
+			a.slice_at_ctx(..i128::MIN),
+			Err(RangeError::EndOutOfRange(isize::MIN))
+		);
+		assert_eq!(
+			a.slice_at_ctx(i128::MIN..),
+			Err(RangeError::StartOutOfRange(isize::MIN))
+		);
+		assert_eq!(
+			a.slice_at_ctx(..=u128::MAX),
+			Err(RangeError::EndOutOfRange(isize::MAX))
+		);
+	}
+
+	#[test]
+	fn test_interleave_ends_at() {
+		assert!([1, 2, 3, 4].interleave_ends_at().eq(&[1, 4, 2, 3]));
+		assert!([1].interleave_ends_at().eq(&[1]));
+		assert_eq!([0u8; 0].interleave_ends_at().next(), None);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_cumsum_mut_at() {
+		let mut a = [1u64, 2, 3];
+		a.cumsum_mut_at(..);
+		assert_eq!(a, [1, 3, 6]);
+		a.cumsum_mut_at(-1..-1);
+		a.cumsum_mut_at(..=0);
+		assert_eq!(a, [1, 3, 6]);
+		a.cumsum_mut_at(-2..);
+		assert_eq!(a, [1, 3, 9]);
+	}
+
+	#[test]
+	fn test_shift_at() {
+		let mut a = [1, 2, 3];
+		a.shift_left_at(0, 0);
+		assert_eq!(a, [1, 2, 3]);
+		a.shift_right_at(1, 0);
+		assert_eq!(a, [0, 1, 2]);
+		a.shift_left_at(3, 7);
+		assert_eq!(a, [7; 3]);
+		a.shift_right_at(usize::MAX, 8);
+		assert_eq!(a, [8; 3]);
+		[0u8; 0].shift_left_at(1, 0);
+	}
+
+	#[test]
+	fn test_as_nonempty() {
+		let single = [7];
+		let s = single.as_nonempty().unwrap();
+		assert_eq!((s.first(), s.last()), (&7, &7));
+		assert_eq!(s.len().get(), 1);
+		assert_eq!(s.try_ref_at(1), None);
+		assert_eq!(s.as_slice(), &[7]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_nth_element_at() {
+		let mut a = [3, 1, 2, 3, 0];
+		a.nth_element_at(-2);
+		assert_eq!(a[3], 3);
+		assert!(a[..3].iter().all(|&x| x <= 3));
+		a.nth_element_at(0);
+		assert_eq!(a[0], 0);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 0 but the index is -1")]
+	fn test_nth_element_at_empty() {
+		[0u8; 0].nth_element_at(-1);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_partition_dedup_at() {
+		let mut a = [1, 1, 2, 2, 3, 3, 3];
+		assert_eq!(a.partition_dedup_at(..), 3);
+		assert_eq!(a[..3], [1, 2, 3]);
+
+		let mut a = [1, 1, 1, 2, 2];
+		assert_eq!(a.partition_dedup_at(-4..-1), 2);
+		assert_eq!(a[..3], [1, 1, 2]);
+		assert_eq!(a[4], 2);
+		assert_eq!([0u8; 0].partition_dedup_at(..), 0);
+	}
+
+	#[test]
+	#[cfg(all(not(feature = "structured-panic"), not(feature = "no-negative")))]
+	#[should_panic(expected = "range out of bounds: the len is 3 but the range is -1..1")]
+	fn test_partition_dedup_at_reversed() {
+		let _ = [1, 2, 3].partition_dedup_at(-1..1);
+	}
+
+	#[test]
+	#[cfg(all(feature = "alloc", not(feature = "no-negative")))]
+	fn test_dedup_within_at() {
+		extern crate std;
+		use crate::VecAt;
+		use std::vec;
+
+		let mut v = vec![1, 1, 2, 2, 3, 3, 4, 4];
+		assert_eq!(v.dedup_within_at(-6..-2), 2);
+		assert_eq!(v, [1, 1, 2, 3, 4, 4]);
+		assert_eq!(v.dedup_within_at(..), 4);
+		assert_eq!(v, [1, 2, 3, 4]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_canonical_index_round_trip() {
+		let a = [1, 2, 3, 4];
+		for idx in -4..0 {
+			assert_eq!(
+				a.canonical_index(idx).and_then(|i| a.to_from_end(i)),
+				Some(idx)
+			);
+		}
+		for i in 0..4 {
+			assert_eq!(
+				a.to_from_end(i).and_then(|idx| a.canonical_index(idx)),
+				Some(i)
+			);
+		}
+		let zsts = [(); usize::MAX];
+		assert_eq!(zsts.to_from_end(isize::MAX as usize - 1), None);
+		assert_eq!(zsts.to_from_end(isize::MAX as usize), Some(isize::MIN));
+	}
+
+	#[test]
+	#[cfg(all(feature = "heapless", not(feature = "no-negative")))]
+	fn test_heapless() {
+		use crate::DequeAt;
+
+		let mut v = heapless::Vec::<i32, 4>::new();
+		v.extend([1, 2, 3]);
+		assert_eq!(v.ref_at(-1), &3);
+		*v.mut_at(-3) = 0;
+		assert_eq!(v, [0, 2, 3]);
+
+		// Wrap around the end of the buffer, so that the elements aren't contiguous.
+		let mut deque = heapless::Deque::<i32, 3>::new();
+		for i in 0..5 {
+			if deque.is_full() {
+				deque.pop_front();
+			}
+			deque.push_back(i).unwrap();
+		}
+		assert_eq!(deque.deque_at(0), 2);
+		assert_eq!(deque.deque_ref_at(-1), &4);
+		*deque.deque_mut_at(-2) = 0;
+		assert!(deque.iter().eq(&[2, 0, 4]));
+	}
+
+	#[test]
+	#[cfg(all(feature = "heapless", not(feature = "structured-panic")))]
+	#[should_panic(expected = "index out of bounds: the len is 0 but the index is -1")]
+	fn test_heapless_deque_out_of_bounds() {
+		use crate::DequeAt;
+
+		let _ = heapless::Deque::<i32, 3>::new().deque_ref_at(-1);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn test_argsort() {
+		let a = [3, 1, 2, 1, 3];
+		assert_eq!(a.argsort(), [1, 3, 2, 0, 4]);
+		assert_eq!(a.argsort_desc(), [0, 4, 2, 1, 3]);
+		assert_eq!(a.argsort_by_key(|&x| x % 3), [0, 4, 1, 3, 2]);
+		assert!([0u8; 0].argsort().is_empty());
+
+		let sorted: [_; 5] = core::array::from_fn(|i| a.at(a.argsort()[i]));
+		assert_eq!(sorted, [1, 1, 2, 3, 3]);
+	}
+
+	#[test]
+	fn test_at_g() {
+		let mut a = [1, 2, 3];
+		unsafe {
+			assert_eq!(a.at_g::<false, _>(0usize), 1);
+			assert_eq!(a.ref_at_g::<false, _>(2u8), &3);
+			*a.mut_at_g::<false, _>(1i32) = 0;
+			*a.mut_at_g::<true, _>(2u16) = 4;
+		}
+		assert_eq!(a, [1, 0, 4]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_at_g_negative() {
+		let mut a = [1, 2, 3];
+		unsafe {
+			assert_eq!(a.at_g::<false, _>(-3), 1);
+			assert_eq!(a.ref_at_g::<false, _>(-1i8), &3);
+			*a.mut_at_g::<true, _>(-2) = 0;
+		}
+		assert_eq!(a, [1, 0, 3]);
+	}
+
+	#[test]
+	#[cfg(not(any(feature = "structured-panic", feature = "unsafe-unchecked")))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+	fn test_at_g_checked() {
+		let _ = unsafe { [1, 2, 3].at_g::<true, _>(3) };
+	}
+
+	#[test]
+	fn test_rolling_hashes() {
+		let text = b"the cat sat on the mat";
+		let direct = |w: &[u8]| {
+			let hash = w.iter().fold(0u128, |hash, &b| {
+				(hash * 257 + u128::from(b)) % ((1 << 61) - 1)
+			});
+			u64::try_from(hash).unwrap()
+		};
+		for window in 1..=text.len() {
+			let hashes = text.rolling_hashes(window);
+			assert!(hashes.eq(text.windows(window).map(direct)));
+		}
+
+		let hashes = [255u8; 40].rolling_hashes(30);
+		assert!(hashes.eq(core::iter::repeat_n(direct(&[255; 30]), 11)));
+		assert_eq!(text.rolling_hashes(0).count(), 0);
+		assert_eq!(text.rolling_hashes(text.len() + 1).count(), 0);
+		assert_eq!(text.rolling_hashes(usize::MAX).count(), 0);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn test_split_for_threads() {
+		let mut a = [1, 2, 3, 4, 5];
+		let chunks = a.split_for_threads(2);
+		assert_eq!(chunks, [&mut [1, 2][..], &mut [3, 4, 5]]);
+
+		let chunks = a.split_for_threads(7);
+		assert_eq!(chunks.len(), 7);
+		assert!(chunks[..6].iter().all(|c| c.is_empty()));
+		assert_eq!(chunks[6], [1, 2, 3, 4, 5]);
+		assert_eq!([0u8; 0].split_for_threads(1), [&mut [][..]]);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	#[should_panic(expected = "can't split a slice into zero chunks")]
+	fn test_split_for_threads_zero() {
+		let _ = [1, 2, 3].split_for_threads(0);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_find_map_at() {
+		let a = [1, 4, 5, 8, 9];
+		let mut calls = 0;
+		let even_half = |x: &i32| {
+			calls += 1;
+			(x % 2 == 0).then_some(x / 2)
+		};
+		assert_eq!(a.find_map_at(even_half), Some((-4, 2)));
+		assert_eq!(calls, 2);
+
+		let (idx, half) = a.rfind_map_at(|x| (x % 2 == 0).then_some(x / 2)).unwrap();
+		assert_eq!((idx, half), (-2, 4));
+		assert_eq!(a.ref_at(idx), &8);
+		assert_eq!([0u8; 0].find_map_at(|&x| Some(x)), None);
+		assert_eq!([1, 3].rfind_map_at(|&x| (x > 3).then_some(x)), None);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_partition_around_at() {
+		let mut a = [5, 1, 4, 2, 3];
+		let boundary = a.partition_around_at(|&x| x < 3);
+		assert_eq!(boundary, -3);
+		assert_eq!(a.slice_at(..boundary), &[1, 2]);
+		let mut rest = [0; 3];
+		rest.copy_from_slice(a.slice_at(boundary..));
+		rest.sort_unstable();
+		assert_eq!(rest, [3, 4, 5]);
+
+		assert_eq!(a.partition_around_at(|_| false), -5);
+		assert_eq!([0u8; 0].partition_around_at(|_| false), 0);
+		assert_eq!([0u8; 0].partition_around_at(|_| true), 0);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn test_sliding_max() {
+		let a = [4, 2, 12, 11, -5, 7, 7, 0];
+		for window in 1..=a.len() {
+			assert!(
+				a.sliding_max(window)
+					.eq(a.windows(window).map(|w| w.iter().max().unwrap()))
+			);
+			assert!(
+				a.sliding_min(window)
+					.eq(a.windows(window).map(|w| w.iter().min().unwrap()))
+			);
+		}
+		assert!(
+			a.sliding_max_by_key(3, |x: &i32| x.abs() % 5)
+				.eq(&[4, 12, 12, 7, 7, 7])
+		);
+		assert_eq!(a.sliding_max(9).count(), 0);
+		assert_eq!(a.sliding_max(usize::MAX).count(), 0);
+		assert_eq!(a.sliding_min(usize::MAX).count(), 0);
+
+		// Ties resolve to the last of the equal elements.
+		let pairs = [(1, 'a'), (1, 'b'), (0, 'c')];
+		assert!(
+			pairs
+				.sliding_max_by_key(2, |p| p.0)
+				.eq(&[(1, 'b'), (1, 'b')])
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	#[should_panic(expected = "window size must be non-zero")]
+	fn test_sliding_max_zero() {
+		let _ = [1, 2, 3].sliding_max(0);
+	}
+
+	#[test]
+	#[cfg(all(feature = "alloc", not(feature = "no-negative")))]
+	fn test_resolve_all() {
+		let a = [1, 2, 3, 4];
+		let indices = [-1i64, 0, -4, 4, -5, 2];
+		let resolved = a.resolve_all(&indices);
+		assert_eq!(resolved, [Some(3), Some(0), Some(0), None, None, Some(2)]);
+		assert_eq!(
+			a.try_resolve_all(&indices),
+			Err(crate::IndexError { index: 4, len: 4 })
+		);
+		assert_eq!(a.try_resolve_all(&indices[..3]), Ok([3, 0, 0].into()));
+		assert_eq!(a.resolve_all(&[0u8; 0]), []);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_resolve_into() {
+		let a = [1, 2, 3, 4];
+		let mut out = [usize::MAX; 3];
+		assert_eq!(
+			a.resolve_into(&[-2, 1, -5], &mut out),
+			Err(crate::IndexError { index: -5, len: 4 })
+		);
+		assert_eq!(out, [2, 1, usize::MAX]);
+		assert_eq!(a.resolve_into(&[3u8, 0, 1], &mut out), Ok(()));
+		assert_eq!(out, [3, 0, 1]);
+	}
+
+	#[test]
+	#[should_panic(expected = "the output must have one position per index")]
+	fn test_resolve_into_mismatched() {
+		let _ = [1, 2, 3].resolve_into(&[0], &mut [0; 2]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_overwrite_at() {
+		let mut a = [1, 2, 3, 4];
+		a.overwrite_at(1, &[5, 6]);
+		assert_eq!(a, [1, 5, 6, 4]);
+		a.overwrite_at(-1, &[7]);
+		assert_eq!(a, [1, 5, 6, 7]);
+		a.overwrite_at(4, &[]);
+		assert_eq!(
+			a.try_overwrite_at(5, &[]),
+			Err(crate::RangeError::StartOutOfRange(5))
+		);
+		assert_eq!(
+			a.try_overwrite_at(usize::MAX, &[0]),
+			Err(crate::RangeError::StartOutOfRange(isize::MAX))
+		);
+		assert_eq!(a, [1, 5, 6, 7]);
+	}
+
+	#[test]
+	#[should_panic(
+		expected = "write out of bounds: the len is 3 but the write is 2 elements at index -1"
+	)]
+	fn test_overwrite_at_past_end() {
+		[1, 2, 3].overwrite_at(-1, &[0, 0]);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_sample_every() {
+		let a = [1, 2, 3, 4, 5, 6, 7];
+		assert!(a.sample_every(3, 0).eq(&[1, 4, 7]));
+		assert!(a.sample_every(3, -1).eq(&[7]));
+		assert!(a.sample_every(10, -7).eq(&[1]));
+		assert_eq!(a.sample_every(1, 7).count(), 0);
+		assert_eq!([0u8; 0].sample_every(2, 0).count(), 0);
+	}
+
+	#[test]
+	#[should_panic(expected = "sample interval must be non-zero")]
+	fn test_sample_every_zero() {
+		let _ = [1, 2, 3].sample_every(0, 0);
+	}
+
+	#[test]
+	#[cfg(not(feature = "structured-panic"))]
+	#[should_panic(expected = "index out of bounds: the len is 3 but the index is -4")]
+	fn test_sample_every_out_of_bounds() {
+		let _ = [1, 2, 3].sample_every(1, -4);
+	}
+
+	#[test]
+	#[cfg(not(feature = "no-negative"))]
+	fn test_index_for_len() {
+		let a = [1, 2, 3];
+		for target_len in 1..=3 {
+			let idx = a.index_for_len(target_len).unwrap();
+			assert_eq!(a.slice_at(..=idx).len(), target_len);
+		}
+		assert_eq!(a.index_for_len(0), None);
+		assert_eq!(a.index_for_len(4), None);
+		assert_eq!([0u8; 0].index_for_len(0), None);
+	}
+
+	#[test]
+	#[cfg(feature = "structured-panic")]
+	fn test_structured_range_panic() {
+		extern crate std;
+		use crate::RangeError;
+
+		let payload = std::panic::catch_unwind(|| [1, 2].slice_at(..3).len()).unwrap_err();
+		assert_eq!(payload.downcast_ref(), Some(&RangeError::EndOutOfRange(3)));
+		let payload = std::panic::catch_unwind(|| {
+			let (mut a, start) = ([1, 2], 2);
+			a.slice_mut_at(start..1).len()
+		})
+		.unwrap_err();
+		assert_eq!(
+			payload.downcast_ref(),
+			Some(&RangeError::Reversed { start: 2, end: 1 })
+		);
 	}
 }
@@ -0,0 +1,37 @@
+use core::ops::Deref;
+
+/// Either a reference to an element or an owned value, as returned by [`At::at_or_owned`](crate::At::at_or_owned).
+/// Both variants dereference to the value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MaybeOwned<'a, T> {
+	/// A reference to an element of the slice.
+	Borrowed(&'a T),
+	/// An owned value, used because the index was out of bounds.
+	Owned(T),
+}
+
+impl<T: Clone> MaybeOwned<'_, T> {
+	/// Return the value, cloning it if it's borrowed.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn into_owned(self) -> T {
+		match self {
+			Self::Borrowed(value) => value.clone(),
+			Self::Owned(value) => value,
+		}
+	}
+}
+
+impl<T> Deref for MaybeOwned<'_, T> {
+	type Target = T;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn deref(&self) -> &T {
+		match self {
+			Self::Borrowed(value) => value,
+			Self::Owned(value) => value,
+		}
+	}
+}
@@ -0,0 +1,72 @@
+#[cfg(debug_assertions)]
+use core::cell::Cell;
+use core::fmt;
+use core::ops::Deref;
+
+/// A reference that checks that it's actually read, as returned by [`At::ref_at_must_use`](crate::At::ref_at_must_use).
+///
+/// Dereferencing the guard (or calling [`MustUse::into_inner`]) marks it as read. If it's dropped
+/// without being read and debug assertions are enabled, it panics. Without debug assertions,
+/// this is just a reference and nothing is checked.
+#[must_use = "the element must be read"]
+pub struct MustUse<'a, T> {
+	value: &'a T,
+	#[cfg(debug_assertions)]
+	read: Cell<bool>,
+}
+
+impl<'a, T> MustUse<'a, T> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn new(value: &'a T) -> Self {
+		Self {
+			value,
+			#[cfg(debug_assertions)]
+			read: Cell::new(false),
+		}
+	}
+
+	/// Mark the guard as read and return the reference it contains.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn into_inner(self) -> &'a T {
+		#[cfg(debug_assertions)]
+		self.read.set(true);
+		self.value
+	}
+}
+
+impl<T> Deref for MustUse<'_, T> {
+	type Target = T;
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn deref(&self) -> &T {
+		#[cfg(debug_assertions)]
+		self.read.set(true);
+		self.value
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for MustUse<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Formatting the guard doesn't count as reading it.
+		f.debug_tuple("MustUse").field(self.value).finish()
+	}
+}
+
+#[cfg(debug_assertions)]
+impl<T> Drop for MustUse<'_, T> {
+	fn drop(&mut self) {
+		// Panicking while already panicking would abort, so the check is skipped when unwinding if possible.
+		#[cfg(feature = "std")]
+		if std::thread::panicking() {
+			return;
+		}
+		assert!(
+			self.read.get(),
+			"an element accessed with `ref_at_must_use` was never read"
+		);
+	}
+}
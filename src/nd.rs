@@ -0,0 +1,89 @@
+use crate::{ToIndex, index_or_panic};
+use ndarray::{ArrayBase, Data, DataMut, Ix1, RawData};
+
+/// This trait provides indexing helpers for one-dimensional `ndarray` arrays and views,
+/// which can't use [`At`](crate::At) since they aren't necessarily contiguous. Requires the `ndarray` feature.
+///
+/// The methods are named differently from those of `At`, since `At` is implemented for every type
+/// and calls with the same name would be ambiguous.
+pub trait NdAt<S: RawData> {
+	/// Access a particular index of a `Copy` type. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::NdAt;
+	/// let arr = ndarray::arr1(&[1, 2, 3]);
+	///
+	/// assert_eq!(arr.view().nd_at(-1), 3);
+	/// # }
+	/// ```
+	fn nd_at(&self, idx: impl ToIndex) -> S::Elem
+	where
+		S: Data,
+		S::Elem: Copy;
+
+	/// Access a particular index by reference. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::NdAt;
+	/// let arr = ndarray::arr1(&[1, 2, 3, 4]);
+	///
+	/// // Views don't need to be contiguous.
+	/// let evens = arr.slice(ndarray::s![..;2]);
+	/// assert_eq!(evens.nd_ref_at(-1), &3);
+	/// # }
+	/// ```
+	fn nd_ref_at(&self, idx: impl ToIndex) -> &S::Elem
+	where
+		S: Data;
+
+	/// Access a particular index by mutable reference. Panics if the index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::NdAt;
+	/// let mut arr = ndarray::arr1(&[1, 2, 3]);
+	///
+	/// *arr.view_mut().nd_mut_at(-1) = 0;
+	/// assert_eq!(arr, ndarray::arr1(&[1, 2, 0]));
+	/// # }
+	/// ```
+	fn nd_mut_at(&mut self, idx: impl ToIndex) -> &mut S::Elem
+	where
+		S: DataMut;
+}
+
+impl<S: RawData> NdAt<S> for ArrayBase<S, Ix1> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn nd_at(&self, idx: impl ToIndex) -> S::Elem
+	where
+		S: Data,
+		S::Elem: Copy,
+	{
+		*self.nd_ref_at(idx)
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn nd_ref_at(&self, idx: impl ToIndex) -> &S::Elem
+	where
+		S: Data,
+	{
+		&self[index_or_panic(idx, self.len())]
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn nd_mut_at(&mut self, idx: impl ToIndex) -> &mut S::Elem
+	where
+		S: DataMut,
+	{
+		let i = index_or_panic(idx, self.len());
+		&mut self[i]
+	}
+}
@@ -0,0 +1,97 @@
+use crate::{ToIndex, check_index, impl_copy_view, index_or_panic};
+use core::num::NonZeroUsize;
+
+/// A slice that is known to be non-empty, as returned by [`At::as_nonempty`](crate::At::as_nonempty).
+///
+/// Since emptiness is checked once when the view is created, accessing the first and last
+/// elements can't fail and doesn't need any further checks. Other indices are still bounds-checked.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::At;
+/// let a = [1, 2, 3];
+/// let nonempty = a.as_nonempty().unwrap();
+///
+/// assert_eq!(nonempty.first(), &1);
+/// assert_eq!(nonempty.last(), &3);
+/// assert_eq!(nonempty.at(-2), 2);
+/// assert!([0u8; 0].as_nonempty().is_none());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct NonEmptySlice<'a, T> {
+	slice: &'a [T],
+}
+
+impl_copy_view!(NonEmptySlice);
+
+impl<'a, T> NonEmptySlice<'a, T> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn new(slice: &'a [T]) -> Option<Self> {
+		(!slice.is_empty()).then_some(Self { slice })
+	}
+
+	/// Return the number of elements, which is never zero.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn len(&self) -> NonZeroUsize {
+		// SAFETY: the slice was checked to be non-empty when the view was created.
+		unsafe { NonZeroUsize::new_unchecked(self.slice.len()) }
+	}
+
+	/// Return the underlying slice.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn as_slice(&self) -> &'a [T] {
+		self.slice
+	}
+
+	/// Access the first element, without checking whether the slice is empty.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn first(&self) -> &'a T {
+		// SAFETY: the slice was checked to be non-empty when the view was created.
+		unsafe { self.slice.get_unchecked(0) }
+	}
+
+	/// Access the last element, without checking whether the slice is empty.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn last(&self) -> &'a T {
+		// SAFETY: the slice was checked to be non-empty when the view was created.
+		unsafe { self.slice.get_unchecked(self.slice.len() - 1) }
+	}
+
+	/// Access a particular index of a `Copy` type. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn at(&self, idx: impl ToIndex) -> T
+	where
+		T: Copy,
+	{
+		*self.ref_at(idx)
+	}
+
+	/// Access a particular index by reference. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn ref_at(&self, idx: impl ToIndex) -> &'a T {
+		&self.slice[index_or_panic(idx, self.slice.len())]
+	}
+
+	/// Access a particular index by reference, or return `None` if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn try_ref_at(&self, idx: impl ToIndex) -> Option<&'a T> {
+		Some(&self.slice[check_index(idx, self.slice.len())?])
+	}
+}
@@ -0,0 +1,33 @@
+use crate::{Position, ToIndex, split_index};
+use std::path::{Component, Path};
+
+/// This trait provides the `component_at` method for paths. Requires the `std` feature.
+pub trait PathAt {
+	/// Return the component of the path at a particular index, or `None` if the index is out of bounds.
+	/// The components are the same as those returned by `Path::components`, so for example
+	/// the root directory of an absolute path is its first component.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::PathAt;
+	/// use std::path::{Component, Path};
+	/// let path = Path::new("/usr/lib/libc.so");
+	///
+	/// assert_eq!(path.component_at(-1), Some(Component::Normal("libc.so".as_ref())));
+	/// assert_eq!(path.component_at(0), Some(Component::RootDir));
+	/// assert_eq!(path.component_at(4), None);
+	/// # }
+	/// ```
+	fn component_at(&self, idx: impl ToIndex) -> Option<Component<'_>>;
+}
+
+impl PathAt for Path {
+	#[inline]
+	fn component_at(&self, idx: impl ToIndex) -> Option<Component<'_>> {
+		match split_index(idx)? {
+			Position::Forward(i) => self.components().nth(i),
+			Position::FromEnd(k) => self.components().nth_back(k - 1),
+		}
+	}
+}
@@ -0,0 +1,304 @@
+use crate::{RangeError, ToIndex, check_bound, check_index, error, to_signed};
+use core::fmt;
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// A range whose bounds may be negative, in which case they count from the end of the slice.
+/// This is usually created implicitly from one of the standard range types, for example `1..-1`,
+/// `-3..` or `..=-2`.
+///
+/// Negative bounds are resolved the same way as negative indices, so `-1` refers to the last element.
+/// This means `..-1` excludes the last element while `..=-1` includes it.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::SignedRange;
+///
+/// assert_eq!(SignedRange::from(1..-1).resolve(5), Some(1..4));
+/// assert_eq!(SignedRange::from(1..=-1).resolve(5), Some(1..5));
+/// assert_eq!(SignedRange::from(-2..).resolve(5), Some(3..5));
+/// assert_eq!(SignedRange::from(3..1).resolve(5), None);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignedRange {
+	// Bounds are stored as an `i128` so that any `usize` or `isize` fits without loss.
+	// Bounds that fit in neither are replaced by `NEGATIVE` or `TOO_LARGE`, which never resolve.
+	start: Bound<i128>,
+	end: Bound<i128>,
+}
+
+// No `usize` or `isize` converts to these, so they can't be confused with a real bound.
+const NEGATIVE: i128 = i128::MIN;
+const TOO_LARGE: i128 = i128::MAX;
+
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn endpoint(idx: impl ToIndex) -> i128 {
+	if let Ok(unsigned_index) = TryInto::<usize>::try_into(idx) {
+		unsigned_index as i128
+	} else if let Some(signed_index) = to_signed(idx) {
+		signed_index as i128
+	} else if error::saturate_index(idx) < 0 {
+		NEGATIVE
+	} else {
+		TOO_LARGE
+	}
+}
+
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn map_bound(bound: Bound<impl ToIndex>) -> Bound<i128> {
+	match bound {
+		Bound::Included(idx) => Bound::Included(endpoint(idx)),
+		Bound::Excluded(idx) => Bound::Excluded(endpoint(idx)),
+		Bound::Unbounded => Bound::Unbounded,
+	}
+}
+
+impl SignedRange {
+	/// Create a range from a pair of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub fn new(start: Bound<impl ToIndex>, end: Bound<impl ToIndex>) -> Self {
+		Self {
+			start: map_bound(start),
+			end: map_bound(end),
+		}
+	}
+
+	/// Resolve this range against a slice length, producing the equivalent `usize` range.
+	/// Returns `None` if either bound is out of bounds or if the resolved start is after the resolved end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::SignedRange;
+	///
+	/// assert_eq!(SignedRange::from(..=-1).resolve(3), Some(0..3));
+	/// assert_eq!(SignedRange::from(-4..).resolve(3), None);
+	/// # }
+	/// ```
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn resolve(self, len: usize) -> Option<Range<usize>> {
+		// An element index is always less than `len`, so adding one to it can't overflow.
+		let start = match self.start {
+			Bound::Included(idx) => check_bound(idx, len)?,
+			Bound::Excluded(idx) => check_index(idx, len)? + 1,
+			Bound::Unbounded => 0,
+		};
+		let end = match self.end {
+			Bound::Included(idx) => check_index(idx, len)? + 1,
+			Bound::Excluded(idx) => check_bound(idx, len)?,
+			Bound::Unbounded => len,
+		};
+
+		(start <= end).then_some(start..end)
+	}
+
+	// Like `resolve`, but reports which part of the range is at fault.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn resolve_or_error(self, len: usize) -> Result<Range<usize>, RangeError> {
+		let start = match self.start {
+			Bound::Included(idx) => {
+				check_bound(idx, len).ok_or(RangeError::StartOutOfRange(saturate(idx)))?
+			}
+			Bound::Excluded(idx) => {
+				check_index(idx, len).ok_or(RangeError::StartOutOfRange(saturate(idx)))? + 1
+			}
+			Bound::Unbounded => 0,
+		};
+		let end = match self.end {
+			Bound::Included(idx) => {
+				check_index(idx, len).ok_or(RangeError::EndOutOfRange(saturate(idx)))? + 1
+			}
+			Bound::Excluded(idx) => {
+				check_bound(idx, len).ok_or(RangeError::EndOutOfRange(saturate(idx)))?
+			}
+			Bound::Unbounded => len,
+		};
+
+		if start <= end {
+			Ok(start..end)
+		} else {
+			Err(RangeError::Reversed { start, end })
+		}
+	}
+}
+
+// Converts a stored bound to an `isize`, saturating bounds that don't fit.
+// `NEGATIVE` and `TOO_LARGE` are outside that range, so they saturate to `isize::MIN` and `isize::MAX`.
+#[allow(clippy::cast_possible_truncation)]
+fn saturate(bound: i128) -> isize {
+	bound.clamp(isize::MIN as i128, isize::MAX as i128) as isize
+}
+
+/// A range that has been resolved against a particular slice length, for slicing repeatedly
+/// without resolving negative bounds each time.
+///
+/// Unlike [`Validated`](crate::Validated), this token doesn't borrow the slice, so it stays usable
+/// while the slice is modified, for example in streaming code that slices the same tail window of
+/// a buffer in a loop. But it's only meaningful for slices of the length it was resolved against:
+/// once the length changes, resolve the range again. Using the token with a slice of a different
+/// length triggers a debug assertion. Without debug assertions, the stale range is used as is,
+/// which may select the wrong elements (or panic if it no longer fits), but is never unsound.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::ResolvedRange;
+/// let mut buf = [1, 2, 3, 4, 5];
+/// let tail = ResolvedRange::new(-2.., buf.len()).unwrap();
+///
+/// for i in 0..3 {
+///     tail.get_mut(&mut buf)[0] += i;
+/// }
+/// assert_eq!(tail.get(&buf), &[7, 5]);
+/// assert_eq!(tail.range(), 3..5);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResolvedRange {
+	start: usize,
+	end: usize,
+	len: usize,
+}
+
+impl ResolvedRange {
+	/// Resolve a range against a slice length, see [`SignedRange::resolve`].
+	/// Returns `None` if either bound is out of bounds or if the resolved start is after the resolved end.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn new(range: impl Into<SignedRange>, len: usize) -> Option<Self> {
+		let Range { start, end } = range.into().resolve(len)?;
+		Some(Self { start, end, len })
+	}
+
+	/// Return the resolved range.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn range(self) -> Range<usize> {
+		self.start..self.end
+	}
+
+	/// Return the slice length that the range was resolved against.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn slice_len(self) -> usize {
+		self.len
+	}
+
+	/// Access the subslice by reference.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn get<T>(self, slice: &[T]) -> &[T] {
+		debug_assert_eq!(slice.len(), self.len, "the slice length has changed");
+		&slice[self.start..self.end]
+	}
+
+	/// Access the subslice by mutable reference.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn get_mut<T>(self, slice: &mut [T]) -> &mut [T] {
+		debug_assert_eq!(slice.len(), self.len, "the slice length has changed");
+		&mut slice[self.start..self.end]
+	}
+}
+
+impl fmt::Debug for SignedRange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Ranges with an excluded start have no literal syntax, so they're shown as a pair of bounds.
+		let (start, end) = (self.start.map(Endpoint), self.end.map(Endpoint));
+		match start {
+			Bound::Included(start) => write!(f, "{start:?}")?,
+			Bound::Excluded(_) => return write!(f, "({start:?}, {end:?})"),
+			Bound::Unbounded => {}
+		}
+		match end {
+			Bound::Included(end) => write!(f, "..={end:?}"),
+			Bound::Excluded(end) => write!(f, "..{end:?}"),
+			Bound::Unbounded => write!(f, ".."),
+		}
+	}
+}
+
+// Shows a stored bound, describing the sentinels instead of printing their values.
+struct Endpoint(i128);
+
+impl fmt::Debug for Endpoint {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.0 {
+			NEGATIVE => f.write_str("<negative>"),
+			TOO_LARGE => f.write_str("<too large>"),
+			bound => write!(f, "{bound}"),
+		}
+	}
+}
+
+impl<I: ToIndex> From<Range<I>> for SignedRange {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from(range: Range<I>) -> Self {
+		Self::new(Bound::Included(range.start), Bound::Excluded(range.end))
+	}
+}
+
+impl<I: ToIndex> From<RangeInclusive<I>> for SignedRange {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from(range: RangeInclusive<I>) -> Self {
+		Self::new(
+			Bound::Included(*range.start()),
+			Bound::Included(*range.end()),
+		)
+	}
+}
+
+impl<I: ToIndex> From<RangeFrom<I>> for SignedRange {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from(range: RangeFrom<I>) -> Self {
+		Self::new(Bound::Included(range.start), Bound::<I>::Unbounded)
+	}
+}
+
+impl<I: ToIndex> From<RangeTo<I>> for SignedRange {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from(range: RangeTo<I>) -> Self {
+		Self::new(Bound::<I>::Unbounded, Bound::Excluded(range.end))
+	}
+}
+
+impl<I: ToIndex> From<RangeToInclusive<I>> for SignedRange {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from(range: RangeToInclusive<I>) -> Self {
+		Self::new(Bound::<I>::Unbounded, Bound::Included(range.end))
+	}
+}
+
+// `(Bound, Bound)` is how `RangeBounds` spells a range with an excluded start.
+impl<I: ToIndex> From<(Bound<I>, Bound<I>)> for SignedRange {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from((start, end): (Bound<I>, Bound<I>)) -> Self {
+		Self::new(start, end)
+	}
+}
+
+impl From<RangeFull> for SignedRange {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn from(_: RangeFull) -> Self {
+		Self::new(Bound::<usize>::Unbounded, Bound::<usize>::Unbounded)
+	}
+}
@@ -0,0 +1,163 @@
+use crate::{ToIndex, check_bound};
+#[cfg(feature = "unsafe-unchecked")]
+use core::hint::unreachable_unchecked;
+
+#[cfg(not(feature = "unsafe-unchecked"))]
+#[inline(never)]
+fn panic_read_check(idx: impl ToIndex, width: usize, unit: &str, len: usize) -> ! {
+	crate::record_oob();
+	panic!("read out of bounds: the len is {len} but the read is {width} {unit} at index {idx:?}")
+}
+
+// Reads `N` elements starting at a particular index, or returns `None` if they don't all fit.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn read_array<T: Copy, const N: usize>(
+	slice: &[T],
+	idx: impl ToIndex,
+) -> Option<[T; N]> {
+	let start = check_bound(idx, slice.len())?;
+	slice[start..].first_chunk().copied()
+}
+
+// Reads `N` elements starting at a particular index, panicking if they don't all fit.
+// `unit` describes the elements in the panic message.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn read_array_or_panic<T: Copy, const N: usize>(
+	slice: &[T],
+	idx: impl ToIndex,
+	#[cfg_attr(feature = "unsafe-unchecked", allow(unused_variables))] unit: &str,
+) -> [T; N] {
+	match read_array(slice, idx) {
+		Some(array) => array,
+		#[cfg(feature = "unsafe-unchecked")]
+		None => unsafe { unreachable_unchecked() },
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		None => panic_read_check(idx, N, unit, slice.len()),
+	}
+}
+
+// Reads `width` bits starting at a particular bit index, in LSB-first order,
+// or returns `None` if they don't all fit. Panics if `width` isn't in `1..=64`.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn read_bits(bytes: &[u8], bit_idx: impl ToIndex, width: u32) -> Option<u64> {
+	assert!(
+		(1..=64).contains(&width),
+		"bit width must be in 1..=64, but it is {width}"
+	);
+	let width = width as usize;
+	let bit_len = bytes.len().saturating_mul(8);
+	let start = check_bound(bit_idx, bit_len)?;
+	if bit_len - start < width {
+		return None;
+	}
+
+	// The bits span at most 9 bytes, which fit in a `u128`.
+	let shift = start % 8;
+	let span = &bytes[start / 8..(start + width).div_ceil(8)];
+	let word = span
+		.iter()
+		.rev()
+		.fold(0, |acc, &b| (acc << 8) | u128::from(b));
+	let mask = u128::MAX >> (128 - width);
+	#[allow(clippy::cast_possible_truncation)]
+	Some(((word >> shift) & mask) as u64)
+}
+
+// Reads `width` bits starting at a particular bit index, panicking if they don't all fit.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn read_bits_or_panic(bytes: &[u8], bit_idx: impl ToIndex, width: u32) -> u64 {
+	match read_bits(bytes, bit_idx, width) {
+		Some(bits) => bits,
+		#[cfg(feature = "unsafe-unchecked")]
+		None => unsafe { unreachable_unchecked() },
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		None => panic_read_check(
+			bit_idx,
+			width as usize,
+			"bits",
+			bytes.len().saturating_mul(8),
+		),
+	}
+}
+
+// Reads a `S` starting at a particular byte index, or returns `None` if it doesn't fit.
+#[cfg(feature = "zerocopy")]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn read_from<S: zerocopy::FromBytes>(bytes: &[u8], byte_idx: impl ToIndex) -> Option<S> {
+	let start = check_bound(byte_idx, bytes.len())?;
+	S::read_from_prefix(&bytes[start..])
+		.ok()
+		.map(|(value, _)| value)
+}
+
+// Reads a `S` starting at a particular byte index, panicking if it doesn't fit.
+#[cfg(feature = "zerocopy")]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn read_from_or_panic<S: zerocopy::FromBytes>(
+	bytes: &[u8],
+	byte_idx: impl ToIndex,
+) -> S {
+	match read_from(bytes, byte_idx) {
+		Some(value) => value,
+		#[cfg(feature = "unsafe-unchecked")]
+		None => unsafe { unreachable_unchecked() },
+		#[cfg(not(feature = "unsafe-unchecked"))]
+		None => panic_read_check(byte_idx, size_of::<S>(), "bytes", bytes.len()),
+	}
+}
+
+// Generates the `read_*_at` and `try_read_*_at` methods of `At`.
+macro_rules! read_int_methods {
+	($($ty:ident, $endian:literal, $from_bytes:ident, $read:ident, $try_read:ident, $example:literal;)*) => {$(
+		#[doc = concat!("Read a ", $endian, "-endian `", stringify!($ty), "` starting at a particular byte index.")]
+		/// Panics if the index is out of bounds or if there aren't enough bytes left to read.
+		///
+		/// # Examples
+		/// ```
+		/// # #[cfg(not(feature = "no-negative"))] {
+		/// use at::At;
+		/// let bytes = [0xff, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+		///
+		#[doc = concat!("assert_eq!(bytes.", stringify!($read), "(-(size_of::<", stringify!($ty), ">() as isize)), ", $example, ");")]
+		/// # }
+		/// ```
+		#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+		#[cfg_attr(feature = "no-force-inline", inline)]
+		fn $read(&self, byte_idx: impl ToIndex) -> $ty
+		where
+			Self: AsRef<[u8]>,
+		{
+			$ty::$from_bytes(read::read_array_or_panic(self.as_ref(), byte_idx, "bytes"))
+		}
+
+		#[doc = concat!("Read a ", $endian, "-endian `", stringify!($ty), "` starting at a particular byte index.")]
+		/// Returns `None` if the index is out of bounds or if there aren't enough bytes left to read.
+		///
+		/// # Examples
+		/// ```
+		/// # #[cfg(not(feature = "no-negative"))] {
+		/// use at::At;
+		/// let bytes = [0xff, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+		///
+		#[doc = concat!("assert_eq!(bytes.", stringify!($try_read), "(-(size_of::<", stringify!($ty), ">() as isize)), Some(", $example, "));")]
+		#[doc = concat!("assert_eq!(bytes.", stringify!($try_read), "(-1), None);")]
+		/// # }
+		/// ```
+		#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+		#[cfg_attr(feature = "no-force-inline", inline)]
+		fn $try_read(&self, byte_idx: impl ToIndex) -> Option<$ty>
+		where
+			Self: AsRef<[u8]>,
+		{
+			read::read_array(self.as_ref(), byte_idx).map($ty::$from_bytes)
+		}
+	)*};
+}
+
+pub(crate) use read_int_methods;
@@ -0,0 +1,88 @@
+use crate::{ToIndex, check_index, impl_copy_view, index_or_panic};
+
+/// A view of a slice in reverse order, as returned by [`At::rev_view`](crate::At::rev_view).
+/// Index `0` is the last element of the underlying slice, and negative indices count from the
+/// end of the reversed order, so `-1` is the first element of the underlying slice.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::At;
+/// let a = [1, 2, 3, 4];
+/// let rev = a.rev_view();
+///
+/// assert_eq!(rev.at(0), 4);
+/// assert_eq!(rev.ref_at(1), &3);
+/// assert_eq!(rev.at(-1), 1);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RevSlice<'a, T> {
+	slice: &'a [T],
+}
+
+impl_copy_view!(RevSlice);
+
+impl<'a, T> RevSlice<'a, T> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn new(slice: &'a [T]) -> Self {
+		Self { slice }
+	}
+
+	/// Return the number of elements.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.slice.len()
+	}
+
+	/// Return whether there are no elements.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.slice.is_empty()
+	}
+
+	/// Return the underlying slice, in its original order.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn as_slice(&self) -> &'a [T] {
+		self.slice
+	}
+
+	/// Access a particular index of the reversed order of a `Copy` type. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn at(&self, idx: impl ToIndex) -> T
+	where
+		T: Copy,
+	{
+		*self.ref_at(idx)
+	}
+
+	/// Access a particular index of the reversed order by reference. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn ref_at(&self, idx: impl ToIndex) -> &'a T {
+		let len = self.slice.len();
+		let i = index_or_panic(idx, len);
+		&self.slice[len - 1 - i]
+	}
+
+	/// Access a particular index of the reversed order by reference,
+	/// or return `None` if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn try_ref_at(&self, idx: impl ToIndex) -> Option<&'a T> {
+		let len = self.slice.len();
+		let i = check_index(idx, len)?;
+		Some(&self.slice[len - 1 - i])
+	}
+}
@@ -0,0 +1,131 @@
+use crate::{ToIndex, index_or_panic};
+use core::mem;
+
+/// A fixed-capacity ring buffer stored in an array, which can be indexed like a slice.
+/// Index `0` is the oldest element and `-1` is the newest one.
+///
+/// The array is always fully initialized, but only `len` elements starting at `head` are part of the ring.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::Ring;
+/// let mut ring = Ring::<i32, 3>::default();
+///
+/// for i in 1..=5 {
+///     ring.push(i);
+/// }
+/// assert_eq!(ring.at(-1), 5);
+/// assert_eq!(ring.at(0), 3);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ring<T, const N: usize> {
+	buf: [T; N],
+	head: usize,
+	len: usize,
+}
+
+impl<T, const N: usize> Ring<T, N> {
+	/// Create a ring from its parts: the backing array, the position of the oldest element
+	/// in the array, and the number of elements in the ring. Elements of the array outside of
+	/// the ring are ignored.
+	///
+	/// # Panics
+	/// Panics if `head` is out of bounds for the array (unless `len` is zero) or if `len` is greater than `N`.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn from_parts(buf: [T; N], head: usize, len: usize) -> Self {
+		assert!(len <= N, "ring length {len} exceeds its capacity {N}");
+		assert!(
+			len == 0 || head < N,
+			"ring head {head} is out of bounds for its capacity {N}"
+		);
+		Self {
+			buf,
+			head: if len == 0 { 0 } else { head },
+			len,
+		}
+	}
+
+	/// Return the number of elements in the ring.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Return whether the ring has no elements.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Append an element as the newest one. If the ring is full, the oldest element is removed and returned.
+	/// A ring with a capacity of zero can't hold anything, so the element is returned immediately.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub fn push(&mut self, value: T) -> Option<T> {
+		if N == 0 {
+			return Some(value);
+		}
+		if self.len < N {
+			let i = self.physical(self.len);
+			self.buf[i] = value;
+			self.len += 1;
+			None
+		} else {
+			let oldest = mem::replace(&mut self.buf[self.head], value);
+			self.head = self.physical(1);
+			Some(oldest)
+		}
+	}
+
+	/// Access a particular index of a `Copy` type. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn at(&self, idx: impl ToIndex) -> T
+	where
+		T: Copy,
+	{
+		*self.ref_at(idx)
+	}
+
+	/// Access a particular index by reference. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn ref_at(&self, idx: impl ToIndex) -> &T {
+		&self.buf[self.physical(index_or_panic(idx, self.len))]
+	}
+
+	/// Access a particular index by mutable reference. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn mut_at(&mut self, idx: impl ToIndex) -> &mut T {
+		let i = self.physical(index_or_panic(idx, self.len));
+		&mut self.buf[i]
+	}
+
+	// Maps a logical index to an index of the array. Requires `i < N`, which also means `N > 0`.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn physical(&self, i: usize) -> usize {
+		let room = N - self.head;
+		if i < room { self.head + i } else { i - room }
+	}
+}
+
+impl<T: Default, const N: usize> Default for Ring<T, N> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn default() -> Self {
+		Self::from_parts(core::array::from_fn(|_| T::default()), 0, 0)
+	}
+}
@@ -0,0 +1,32 @@
+use alloc::collections::VecDeque;
+
+// Yields the extreme element of every window of `window` elements, in linear time.
+// `beats(new, old)` returns whether `new` should replace `old` as a candidate. Candidates that are
+// beaten can never be the extreme of a later window, so the deque of candidates stays monotonic.
+// Panics if `window` is zero.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub(crate) fn sliding_extremes<'a, T>(
+	slice: &'a [T],
+	window: usize,
+	mut beats: impl FnMut(&T, &T) -> bool + 'a,
+) -> impl Iterator<Item = &'a T> + 'a {
+	assert!(window != 0, "window size must be non-zero");
+	let mut candidates = VecDeque::new();
+
+	(0..slice.len()).filter_map(move |i| {
+		while candidates
+			.back()
+			.is_some_and(|&j: &usize| beats(&slice[i], &slice[j]))
+		{
+			candidates.pop_back();
+		}
+		candidates.push_back(i);
+		// Candidates are never newer than `i`, so unlike `j + window`, this can't overflow.
+		if candidates.front().is_some_and(|&j| i - j >= window) {
+			candidates.pop_front();
+		}
+		// The front candidate is the oldest one, and it beats every other candidate.
+		(i + 1 >= window).then(|| &slice[candidates[0]])
+	})
+}
@@ -0,0 +1,92 @@
+use crate::{ToIndex, check_index, impl_copy_view, index_or_panic};
+
+/// A stack stored in a slice with a separate stack pointer, which is indexed by depth from the top.
+/// Only the first `sp` elements are live, and depth `0` is the top of the stack, at `sp - 1`.
+///
+/// In other words, the live elements are indexed in reverse, so a negative depth counts up from
+/// the bottom instead: `-1` is the bottom of the stack, at `0`. Depths are checked against `sp`
+/// rather than the length of the slice.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::Stack;
+/// let values = [1, 2, 3, 0, 0];
+/// let stack = Stack::new(&values, 3);
+///
+/// assert_eq!(stack.at(0), 3);
+/// assert_eq!(stack.ref_at(1), &2);
+/// assert_eq!(stack.at(-1), 1);
+/// assert_eq!(stack.try_ref_at(3), None);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Stack<'a, T> {
+	slice: &'a [T],
+	sp: usize,
+}
+
+impl_copy_view!(Stack);
+
+impl<'a, T> Stack<'a, T> {
+	/// Create a stack from its storage and its stack pointer, which is the number of live elements.
+	///
+	/// # Panics
+	/// Panics if the stack pointer is greater than the length of the slice.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn new(slice: &'a [T], sp: usize) -> Self {
+		assert!(
+			sp <= slice.len(),
+			"the len is {} but the stack pointer is {sp}",
+			slice.len()
+		);
+		Self { slice, sp }
+	}
+
+	/// Return the stack pointer, which is the number of live elements.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn sp(&self) -> usize {
+		self.sp
+	}
+
+	/// Return the live elements, from the bottom of the stack to the top.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn as_slice(&self) -> &'a [T] {
+		&self.slice[..self.sp]
+	}
+
+	/// Access the element at a particular depth of a `Copy` type. Panics if the depth is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn at(&self, depth: impl ToIndex) -> T
+	where
+		T: Copy,
+	{
+		*self.ref_at(depth)
+	}
+
+	/// Access the element at a particular depth by reference. Panics if the depth is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn ref_at(&self, depth: impl ToIndex) -> &'a T {
+		let i = index_or_panic(depth, self.sp);
+		&self.slice[self.sp - 1 - i]
+	}
+
+	/// Access the element at a particular depth by reference, or return `None` if the depth is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn try_ref_at(&self, depth: impl ToIndex) -> Option<&'a T> {
+		let i = check_index(depth, self.sp)?;
+		Some(&self.slice[self.sp - 1 - i])
+	}
+}
@@ -0,0 +1,95 @@
+use crate::{ToIndex, check_index, impl_copy_view, index_or_panic};
+
+/// A view of every `stride`-th element of a slice, starting at a base offset.
+/// This is useful for accessing a column of a matrix stored in row-major order,
+/// where the base is the column and the stride is the number of columns.
+///
+/// Indices are resolved against the number of elements in the view, so `-1` is the last one.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::StridedView;
+/// let m = [1, 2, 3, 4, 5, 6];
+/// let column = StridedView::new(&m, 1, 3);
+///
+/// assert_eq!(column.len(), 2);
+/// assert_eq!(column.ref_at(0), &2);
+/// assert_eq!(column.at(-1), 5);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct StridedView<'a, T> {
+	slice: &'a [T],
+	stride: usize,
+}
+
+impl_copy_view!(StridedView);
+
+impl<'a, T> StridedView<'a, T> {
+	/// View every `stride`-th element of a slice, starting at `base`.
+	/// The base may be equal to the length of the slice, in which case the view is empty.
+	///
+	/// # Panics
+	/// Panics if the stride is zero or if the base is greater than the length of the slice.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn new(slice: &'a [T], base: usize, stride: usize) -> Self {
+		assert!(stride != 0, "the stride must not be zero");
+		assert!(
+			base <= slice.len(),
+			"the len is {} but the base is {base}",
+			slice.len()
+		);
+		Self {
+			slice: &slice[base..],
+			stride,
+		}
+	}
+
+	/// Return the number of elements in the view.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.slice.len().div_ceil(self.stride)
+	}
+
+	/// Return whether the view has no elements.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.slice.is_empty()
+	}
+
+	/// Access a particular element of the view of a `Copy` type. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn at(&self, idx: impl ToIndex) -> T
+	where
+		T: Copy,
+	{
+		*self.ref_at(idx)
+	}
+
+	/// Access a particular element of the view by reference. Panics if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn ref_at(&self, idx: impl ToIndex) -> &'a T {
+		let i = index_or_panic(idx, self.len());
+		&self.slice[i * self.stride]
+	}
+
+	/// Access a particular element of the view by reference, or return `None` if the index is out of bounds.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn try_ref_at(&self, idx: impl ToIndex) -> Option<&'a T> {
+		let i = check_index(idx, self.len())?;
+		Some(&self.slice[i * self.stride])
+	}
+}
@@ -0,0 +1,113 @@
+use crate::{BorderMode, ToIndex, index_with_or_panic};
+
+/// A grid stored in row-major order in a flat slice, whose rows and columns both wrap around,
+/// as in cellular automata. The row is resolved against the number of rows and the column against
+/// the width, and indices that are out of bounds wrap to the other side instead of panicking.
+/// So `(-1, -1)` is the bottom-right cell, and `(0, -1)` is the top-right cell.
+///
+/// The storage can be anything that can be borrowed as a slice, such as an array, a `Vec`
+/// or a mutable reference to a slice.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::TorusGrid;
+/// let mut grid = TorusGrid::new([1, 2, 3, 4, 5, 6], 3);
+///
+/// assert_eq!(grid.at2(-1, -1), &6);
+/// assert_eq!(grid.copy_at2(2, 3), 1);
+///
+/// *grid.mut_at2(0, -4) = 0;
+/// assert_eq!(grid.into_inner(), [1, 2, 0, 4, 5, 6]);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TorusGrid<S> {
+	data: S,
+	width: usize,
+}
+
+impl<S> TorusGrid<S> {
+	/// Create a grid from its storage and its width, which is the number of columns.
+	///
+	/// # Panics
+	/// Panics if the storage is empty or if its length isn't a multiple of the width.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn new<T>(data: S, width: usize) -> Self
+	where
+		S: AsRef<[T]>,
+	{
+		let len = data.as_ref().len();
+		assert!(
+			width != 0 && len != 0 && len % width == 0,
+			"the len is {len} but the width is {width}"
+		);
+		Self { data, width }
+	}
+
+	/// Return the number of columns.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Return the storage.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn into_inner(self) -> S {
+		self.data
+	}
+
+	// Resolves a cell to its position in the storage, wrapping both axes.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn cell(&self, row: impl ToIndex, col: impl ToIndex, len: usize) -> usize {
+		let r = index_with_or_panic(row, len / self.width, BorderMode::Wrap);
+		let c = index_with_or_panic(col, self.width, BorderMode::Wrap);
+		r * self.width + c
+	}
+
+	/// Access a cell by reference, wrapping both axes.
+	/// Panics only if an index fits in neither a `usize` nor an `isize`.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn at2<T>(&self, row: impl ToIndex, col: impl ToIndex) -> &T
+	where
+		S: AsRef<[T]>,
+	{
+		let slice = self.data.as_ref();
+		&slice[self.cell(row, col, slice.len())]
+	}
+
+	/// Access a cell of a `Copy` type, wrapping both axes.
+	/// Panics only if an index fits in neither a `usize` nor an `isize`.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn copy_at2<T: Copy>(&self, row: impl ToIndex, col: impl ToIndex) -> T
+	where
+		S: AsRef<[T]>,
+	{
+		*self.at2(row, col)
+	}
+
+	/// Access a cell by mutable reference, wrapping both axes.
+	/// Panics only if an index fits in neither a `usize` nor an `isize`.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn mut_at2<T>(&mut self, row: impl ToIndex, col: impl ToIndex) -> &mut T
+	where
+		S: AsMut<[T]>,
+	{
+		let len = self.data.as_mut().len();
+		let i = self.cell(row, col, len);
+		&mut self.data.as_mut()[i]
+	}
+}
@@ -0,0 +1,53 @@
+use crate::impl_copy_view;
+
+/// An index that has been checked to be in bounds for a slice, as returned by [`At::validate`](crate::At::validate).
+///
+/// The token keeps the slice borrowed, so the slice can't change while the token exists
+/// and accessing the element through it doesn't need another bounds check.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no-negative"))] {
+/// use at::At;
+/// let a = [1, 2, 3];
+/// let last = a.validate(-1).unwrap();
+///
+/// for _ in 0..3 {
+///     assert_eq!(last.get(), &3);
+/// }
+/// assert_eq!(last.index(), 2);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Validated<'a, T> {
+	slice: &'a [T],
+	index: usize,
+}
+
+impl_copy_view!(Validated);
+
+impl<'a, T> Validated<'a, T> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	pub(crate) fn new(slice: &'a [T], index: usize) -> Option<Self> {
+		(index < slice.len()).then_some(Self { slice, index })
+	}
+
+	/// Return the resolved index.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn index(self) -> usize {
+		self.index
+	}
+
+	/// Access the element without checking the index again.
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	#[must_use]
+	pub fn get(self) -> &'a T {
+		// SAFETY: the index was checked when the token was created,
+		// and the slice has been borrowed ever since, so its length hasn't changed.
+		unsafe { self.slice.get_unchecked(self.index) }
+	}
+}
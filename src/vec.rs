@@ -0,0 +1,187 @@
+#[cfg(not(feature = "unsafe-unchecked"))]
+use crate::panic_bounds_check;
+use crate::{At, Position, SignedRange, ToIndex, check_bound, check_index, split_index};
+use alloc::vec::Vec;
+#[cfg(feature = "unsafe-unchecked")]
+use core::hint::unreachable_unchecked;
+
+/// This trait provides indexing helpers that need to grow or shrink a `Vec`,
+/// which isn't possible through `AsMut<[T]>`. Requires the `alloc` feature.
+pub trait VecAt<T> {
+	/// Access a particular index by mutable reference, or append a new element if the index is
+	/// equal to the length. The new element is created by `make`, which is only called in that case.
+	/// Panics if the index is otherwise out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::VecAt;
+	/// let mut v = vec![1, 2];
+	///
+	/// *v.at_or_push(-1, || 0) += 10;
+	/// *v.at_or_push(2, || 0) += 10;
+	/// assert_eq!(v, [1, 12, 10]);
+	/// # }
+	/// ```
+	fn at_or_push(&mut self, idx: impl ToIndex, make: impl FnOnce() -> T) -> &mut T;
+
+	/// Remove consecutive repeated elements, keeping the *last* element of each run.
+	/// This is the counterpart to `Vec::dedup`, which keeps the first one.
+	///
+	/// # Examples
+	/// ```
+	/// use at::VecAt;
+	///
+	/// // Readings compare equal by value, regardless of when they were taken.
+	/// struct Reading { value: u8, time: u32 }
+	/// impl PartialEq for Reading {
+	///     fn eq(&self, other: &Self) -> bool {
+	///         self.value == other.value
+	///     }
+	/// }
+	///
+	/// let mut v: Vec<_> = [(1, 0), (1, 1), (2, 2), (1, 3), (1, 4)]
+	///     .map(|(value, time)| Reading { value, time })
+	///     .into();
+	///
+	/// v.dedup_keep_last();
+	/// assert_eq!(v.iter().map(|r| r.time).collect::<Vec<_>>(), [1, 2, 4]);
+	/// ```
+	fn dedup_keep_last(&mut self)
+	where
+		T: PartialEq;
+
+	/// Remove all but the last `n` elements, or do nothing if there are at most `n` elements.
+	/// This is the counterpart to `Vec::truncate`, which keeps the first `n` elements.
+	/// The remaining elements are moved to the front without reallocating.
+	///
+	/// # Examples
+	/// ```
+	/// use at::VecAt;
+	/// let mut v = vec![1, 2, 3, 4, 5];
+	///
+	/// v.keep_last(2);
+	/// assert_eq!(v, [4, 5]);
+	/// v.keep_last(3);
+	/// assert_eq!(v, [4, 5]);
+	/// ```
+	fn keep_last(&mut self, n: usize);
+
+	/// Access a particular index by mutable reference, growing the `Vec` with default elements if a
+	/// non-negative index is past the end. Negative indices never grow the `Vec` and are resolved
+	/// against its current length. Panics if a negative index is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::VecAt;
+	/// let mut counts = vec![1];
+	///
+	/// *counts.mut_at_grow(3) += 1;
+	/// *counts.mut_at_grow(-1) += 1;
+	/// assert_eq!(counts, [1, 0, 0, 2]);
+	/// # }
+	/// ```
+	fn mut_at_grow(&mut self, idx: impl ToIndex) -> &mut T
+	where
+		T: Default;
+
+	/// Remove consecutive repeated elements within a subslice, like `Vec::dedup`, shifting the following
+	/// elements down. Returns the new length of the subslice. The bounds of the range may be negative,
+	/// see [`SignedRange`]. Panics if either bound is out of bounds or if the start is after the end.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "no-negative"))] {
+	/// use at::VecAt;
+	/// let mut v = vec![1, 1, 2, 2, 3, 3, 4];
+	///
+	/// assert_eq!(v.dedup_within_at(-5..-1), 2);
+	/// assert_eq!(v, [1, 1, 2, 3, 4]);
+	/// # }
+	/// ```
+	fn dedup_within_at(&mut self, range: impl Into<SignedRange>) -> usize
+	where
+		T: PartialEq;
+}
+
+impl<T> VecAt<T> for Vec<T> {
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn at_or_push(&mut self, idx: impl ToIndex, make: impl FnOnce() -> T) -> &mut T {
+		let len = self.len();
+
+		match check_bound(idx, len) {
+			Some(i) if i == len => {
+				self.push(make());
+				&mut self[i]
+			}
+			Some(i) => &mut self[i],
+			#[cfg(feature = "unsafe-unchecked")]
+			None => unsafe { unreachable_unchecked() },
+			#[cfg(not(feature = "unsafe-unchecked"))]
+			None => panic_bounds_check(idx, len),
+		}
+	}
+
+	#[inline]
+	fn dedup_keep_last(&mut self)
+	where
+		T: PartialEq,
+	{
+		// `dedup_by` keeps `earlier` and removes `later`, so swapping them keeps the later one instead.
+		self.dedup_by(|later, earlier| {
+			let same = later == earlier;
+			if same {
+				core::mem::swap(later, earlier);
+			}
+			same
+		});
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn keep_last(&mut self, n: usize) {
+		let excess = self.len().saturating_sub(n);
+		self.drain(..excess);
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn mut_at_grow(&mut self, idx: impl ToIndex) -> &mut T
+	where
+		T: Default,
+	{
+		let len = self.len();
+
+		if let Some(Position::Forward(i)) = split_index(idx) {
+			if i >= len {
+				// An index of `usize::MAX` is never in bounds, so saturating just leads to a panic.
+				self.resize_with(i.saturating_add(1), T::default);
+			}
+			return &mut self[i];
+		}
+		match check_index(idx, len) {
+			Some(i) => &mut self[i],
+			#[cfg(feature = "unsafe-unchecked")]
+			None => unsafe { unreachable_unchecked() },
+			#[cfg(not(feature = "unsafe-unchecked"))]
+			None => panic_bounds_check(idx, len),
+		}
+	}
+
+	#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+	#[cfg_attr(feature = "no-force-inline", inline)]
+	fn dedup_within_at(&mut self, range: impl Into<SignedRange>) -> usize
+	where
+		T: PartialEq,
+	{
+		let range = range.into();
+		// This panics if the range doesn't resolve, so the range always resolves below.
+		let kept = self.partition_dedup_at(range);
+		if let Some(r) = range.resolve(self.len()) {
+			self.drain(r.start + kept..r.end);
+		}
+		kept
+	}
+}